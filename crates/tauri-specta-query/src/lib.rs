@@ -63,9 +63,25 @@
 //!   command and return query options;
 //! - `mutations`, whose zero-argument methods return mutation options;
 //! - `queryKeys`, for matching or invalidating query caches (its arguments are
-//!   partial, so prefixes can be constructed); and
+//!   a partial object keyed by argument name, so prefixes can be constructed);
+//!   and
 //! - `mutationKeys`, for matching mutation caches.
 //!
+//! Keys start with the root set with [`CommandSet::key_root`], which defaults
+//! to the plugin name set with [`CommandSet::plugin_name`], or `"app"`,
+//! followed by the namespace set with [`CommandSet::namespace`], if any. Both key objects have an `all` key matching every command, and an
+//! object per namespace with its own `all` key.
+//!
+//! Commands added with [`CommandSet::infinite_queries`] are also exported from
+//! an `infiniteQueries` object, whose methods accept the arguments other than
+//! the cursor as one object and return infinite query options.
+//!
+//! Events declared with [`CommandSet::invalidate_on`] also generate a
+//! `subscribeInvalidations(queryClient)` function, which invalidates the
+//! affected queries whenever the backend emits one of the events. Mutations
+//! declared with [`CommandSet::invalidates`] accept an optional `QueryClient`
+//! and invalidate their queries when they succeed.
+//!
 //! For React Query, the commands above can be consumed like this:
 //!
 //! ```tsx
@@ -76,7 +92,7 @@
 //! const queryClient = useQueryClient();
 //! const renameUser = useMutation({
 //!   ...mutations.renameUser(),
-//!   onSuccess: () => queryClient.invalidateQueries({ queryKey: queryKeys.getUser(1) }),
+//!   onSuccess: () => queryClient.invalidateQueries({ queryKey: queryKeys.getUser({ id: 1 }) }),
 //! });
 //!
 //! renameUser.mutate({ id: 1, name: "Ada" });
@@ -92,12 +108,23 @@
 //! command; keep `#[tauri::command(rename_all = "...")]` and
 //! `#[specta(rename_all = "...")]` aligned when using it.
 //!
+//! Commands which return a [`Result`] are unwrapped, so TanStack Query sees
+//! failed commands as errors typed as the command's error. Set the error
+//! handling mode with [`CommandSet::error_handling`]. [`CommandSet::build`]
+//! renders the helpers and configures the returned builder with that same
+//! mode, so don't change it on the returned builder.
+//!
+//! Default options like `staleTime` or `retry` can be declared per command with
+//! [`CommandSet::query_options`] and [`CommandSet::mutation_options`], and are
+//! merged into the generated options.
+//!
 //! # Frameworks
 //!
 //! Choose the matching [`TanstackQueryFramework`] variant for React, Solid, Vue,
 //! Angular, Svelte, or Preact. The generated helpers use that framework's
-//! TanStack Query package. Svelte helpers return option-producing functions and
-//! do not generate an import.
+//! TanStack Query package. Svelte helpers return option-producing functions,
+//! which are annotated with the option types of `@tanstack/svelte-query` when
+//! the command's result is unwrapped.
 //!
 //! # Additional bindings
 //!
@@ -112,13 +139,22 @@
     html_favicon_url = "https://github.com/specta-rs/specta/raw/main/.github/logo-128.png"
 )]
 
-use std::{borrow::Cow, collections::BTreeMap, sync::Arc};
+use std::{
+    any::{TypeId, type_name},
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    time::Duration,
+};
 
 use heck::ToLowerCamelCase;
 use serde::Serialize;
-use specta::{Type, Types, datatype};
+use specta::{
+    Type, Types,
+    datatype::{self, DataType, Reference},
+};
 use tauri::{Runtime, ipc::Invoke};
-use tauri_specta::{Casing, Commands, Events};
+use tauri_specta::{Casing, Commands, ErrorHandlingMode, EventDirection, Events};
 
 /// A collection of query commands, mutation commands, and their shared bindings.
 ///
@@ -127,11 +163,21 @@ use tauri_specta::{Casing, Commands, Events};
 pub struct CommandSet<R: Runtime> {
     handler: Arc<dyn Fn(Invoke<R>) -> bool + Send + Sync + 'static>,
     queries: Vec<datatype::Function>,
+    infinite_queries: Vec<(datatype::Function, Pagination)>,
     mutations: Vec<datatype::Function>,
+    invalidations: BTreeMap<TypeId, (&'static str, BTreeSet<Cow<'static, str>>)>,
+    mutation_invalidations: BTreeMap<Cow<'static, str>, Vec<Invalidation>>,
+    query_options: BTreeMap<Cow<'static, str>, QueryOptions>,
+    mutation_options: BTreeMap<Cow<'static, str>, MutationOptions>,
     types: Types,
     events: Events,
     constants: BTreeMap<Cow<'static, str>, serde_json::Value>,
     function_casing: Casing,
+    error_handling: ErrorHandlingMode,
+    namespace: Option<Cow<'static, str>>,
+    namespaces: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    key_root: Option<Cow<'static, str>>,
+    plugin_name: Option<&'static str>,
 }
 
 impl<R: Runtime> CommandSet<R> {
@@ -157,12 +203,99 @@ impl<R: Runtime> CommandSet<R> {
                 }) || (mutation_invoke)(i)
             }),
             queries,
+            infinite_queries: Default::default(),
             mutations,
+            invalidations: Default::default(),
+            mutation_invalidations: Default::default(),
+            query_options: Default::default(),
+            mutation_options: Default::default(),
             types,
             events: Default::default(),
             constants: Default::default(),
             function_casing: Default::default(),
+            error_handling: Default::default(),
+            namespace: None,
+            namespaces: Default::default(),
+            key_root: None,
+            plugin_name: None,
+        }
+    }
+
+    /// Adds cursor-paginated query commands, which generate `infiniteQueryOptions`.
+    ///
+    /// Each command must take the argument named by
+    /// [`Pagination::new`], which receives the cursor of the page to fetch. The
+    /// generated `infiniteQueries` methods accept the other arguments as one
+    /// object, like mutations. The cursor of the next page is read from the
+    /// given field of the command's result, and no more pages are fetched once
+    /// it is `null`.
+    ///
+    /// This can be called multiple times to add commands with different
+    /// pagination.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use tauri_specta::collect_commands;
+    /// use tauri_specta_query::{CommandSet, Pagination};
+    ///
+    /// #[derive(Serialize, specta::Type)]
+    /// struct UserPage {
+    ///     users: Vec<String>,
+    ///     next_cursor: Option<u32>,
+    /// }
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn list_users(cursor: Option<u32>) -> UserPage {
+    ///     # let _ = cursor;
+    ///     UserPage { users: vec![], next_cursor: None }
+    /// }
+    ///
+    /// let commands = CommandSet::<tauri::Wry>::new(
+    ///     collect_commands![],
+    ///     collect_commands![],
+    /// )
+    /// .infinite_queries(
+    ///     collect_commands![list_users],
+    ///     Pagination::new("cursor", "next_cursor"),
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a command doesn't take the cursor argument.
+    #[track_caller]
+    #[must_use]
+    pub fn infinite_queries(mut self, commands: Commands<R>, pagination: Pagination) -> Self {
+        let Commands(infinite_query_invoke, infinite_query_types) = commands;
+        let functions = infinite_query_types(&mut self.types);
+
+        for function in &functions {
+            assert!(
+                function
+                    .args
+                    .iter()
+                    .any(|(arg, _)| *arg == pagination.cursor_argument),
+                "Infinite query command '{}' has no cursor argument '{}'",
+                function.name,
+                pagination.cursor_argument
+            );
         }
+
+        let handler = self.handler.clone();
+        self.handler = Arc::new(move |i| {
+            (handler)(Invoke {
+                message: i.message.clone(),
+                resolver: i.resolver.clone(),
+                acl: i.acl.clone(),
+            }) || (infinite_query_invoke)(i)
+        });
+        self.infinite_queries.extend(
+            functions
+                .into_iter()
+                .map(|function| (function, pagination.clone())),
+        );
+        self
     }
 
     /// Registers the events to include in the generated bindings.
@@ -173,6 +306,126 @@ impl<R: Runtime> CommandSet<R> {
         Self { events, ..self }
     }
 
+    /// Invalidates queries whenever the backend emits the event `E`.
+    ///
+    /// `E` is an [`Event`](tauri_specta::Event) or an
+    /// [`EventGroup`](tauri_specta::EventGroup), in which case every event of
+    /// the group invalidates the queries. `queries` are query or infinite query
+    /// commands collected with [`tauri_specta::collect_commands!`]. The
+    /// generated bindings export a `subscribeInvalidations(queryClient)`
+    /// function which listens to the events through the generated `events`
+    /// object and invalidates every cached instance of the queries, returning
+    /// a function which stops listening.
+    ///
+    /// The event must also be registered with [`CommandSet::events`], under
+    /// any name.
+    ///
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    /// use tauri_specta::{Event, collect_commands, collect_events};
+    /// use tauri_specta_query::CommandSet;
+    ///
+    /// #[derive(Clone, Serialize, Deserialize, specta::Type, Event)]
+    /// struct UserUpdated {
+    ///     id: u32,
+    /// }
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn get_user(id: u32) -> String {
+    ///     format!("user-{id}")
+    /// }
+    ///
+    /// let commands = CommandSet::<tauri::Wry>::new(
+    ///     collect_commands![get_user],
+    ///     collect_commands![],
+    /// )
+    /// .events(collect_events![UserUpdated])
+    /// .invalidate_on::<UserUpdated>(collect_commands![get_user]);
+    /// ```
+    ///
+    /// ```ts
+    /// import { QueryClient } from "@tanstack/react-query";
+    /// import { subscribeInvalidations } from "./bindings";
+    ///
+    /// const queryClient = new QueryClient();
+    /// const unsubscribe = subscribeInvalidations(queryClient);
+    /// ```
+    ///
+    /// [`CommandSet::build`] panics if the event or a query isn't registered
+    /// on the command set, or if the event's direction is
+    /// [`EventDirection::ToBackend`].
+    #[must_use]
+    pub fn invalidate_on<E: 'static>(mut self, queries: Commands<R>) -> Self {
+        let Commands(_, query_types) = queries;
+        self.invalidations
+            .entry(TypeId::of::<E>())
+            .or_insert_with(|| (type_name::<E>(), Default::default()))
+            .1
+            .extend(
+                query_types(&mut Types::default())
+                    .into_iter()
+                    .map(|function| function.name),
+            );
+        self
+    }
+
+    /// Invalidates queries whenever a mutation succeeds.
+    ///
+    /// `mutation` is the Rust name of a mutation command and each
+    /// [`Invalidation`] names a query or infinite query command, so a plain
+    /// name invalidates every cached instance of that query. Use
+    /// [`Invalidation::arg`] to only invalidate the instances whose arguments
+    /// match the mutation's input.
+    ///
+    /// The generated mutation methods accept an optional `QueryClient`, which
+    /// is used to invalidate the queries in `onSuccess`. The query keys are
+    /// also exposed as `meta.invalidates(input)` and through the generated
+    /// `mutationInvalidations` object, so a global `MutationCache` callback
+    /// can invalidate them instead.
+    ///
+    /// ```rust
+    /// use tauri_specta::collect_commands;
+    /// use tauri_specta_query::{CommandSet, Invalidation};
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn get_user(id: u32) -> String {
+    ///     format!("user-{id}")
+    /// }
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn rename_user(id: u32, name: String) {
+    ///     # let _ = (id, name);
+    /// }
+    ///
+    /// let commands = CommandSet::<tauri::Wry>::new(
+    ///     collect_commands![get_user],
+    ///     collect_commands![rename_user],
+    /// )
+    /// .invalidates("rename_user", [Invalidation::new("get_user").arg("id", "id")]);
+    /// ```
+    ///
+    /// ```ts
+    /// const renameUser = useMutation(mutations.renameUser(useQueryClient()));
+    /// ```
+    ///
+    /// [`CommandSet::build`] panics if the mutation or a query isn't registered
+    /// on the command set, or if an argument mapping doesn't match them.
+    #[must_use]
+    pub fn invalidates(
+        mut self,
+        mutation: impl Into<Cow<'static, str>>,
+        queries: impl IntoIterator<Item = impl Into<Invalidation>>,
+    ) -> Self {
+        self.mutation_invalidations
+            .entry(mutation.into())
+            .or_default()
+            .extend(queries.into_iter().map(Into::into));
+        self
+    }
+
     /// Replaces the type registry used by this command set.
     ///
     /// Prefer [`CommandSet::typ`] when adding individual standalone types. This
@@ -238,11 +491,150 @@ impl<R: Runtime> CommandSet<R> {
         self
     }
 
+    /// Sets the default TanStack Query options of a query or infinite query command.
+    ///
+    /// `query` is the Rust name of the command. The options are merged into
+    /// the generated query options, and can still be overridden where the
+    /// options are used. Setting the options of a command again replaces them.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use tauri_specta::collect_commands;
+    /// use tauri_specta_query::{CommandSet, QueryOptions};
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn get_user(id: Option<u32>) -> String {
+    ///     format!("user-{id:?}")
+    /// }
+    ///
+    /// let commands = CommandSet::<tauri::Wry>::new(
+    ///     collect_commands![get_user],
+    ///     collect_commands![],
+    /// )
+    /// .query_options(
+    ///     "get_user",
+    ///     QueryOptions::new()
+    ///         .stale_time(Duration::from_secs(60))
+    ///         .enabled_when_set("id"),
+    /// );
+    /// ```
+    ///
+    /// [`CommandSet::build`] panics if the query isn't registered on the
+    /// command set, or if an argument of the options doesn't belong to it.
+    #[must_use]
+    pub fn query_options(
+        mut self,
+        query: impl Into<Cow<'static, str>>,
+        options: QueryOptions,
+    ) -> Self {
+        self.query_options.insert(query.into(), options);
+        self
+    }
+
+    /// Sets the default TanStack Query options of a mutation command.
+    ///
+    /// `mutation` is the Rust name of the command. The options are merged into
+    /// the generated mutation options. Setting the options of a command again
+    /// replaces them.
+    ///
+    /// [`CommandSet::build`] panics if the mutation isn't registered on the
+    /// command set.
+    #[must_use]
+    pub fn mutation_options(
+        mut self,
+        mutation: impl Into<Cow<'static, str>>,
+        options: MutationOptions,
+    ) -> Self {
+        self.mutation_options.insert(mutation.into(), options);
+        self
+    }
+
+    /// Nests the keys of the commands in this set under a namespace.
+    ///
+    /// The generated `queryKeys` and `mutationKeys` objects gain a property
+    /// named after the namespace, with an `all` key matching every command in
+    /// it. This is useful to group the commands of a module before
+    /// [merging](CommandSet::merge) command sets. The namespace applies to
+    /// every command of the set, including those added after it's set, and
+    /// replaces the namespaces of command sets merged into this one.
+    ///
+    /// ```rust
+    /// use tauri_specta::collect_commands;
+    /// use tauri_specta_query::CommandSet;
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn get_user(id: u32) -> String {
+    ///     format!("user-{id}")
+    /// }
+    ///
+    /// let users = CommandSet::<tauri::Wry>::new(
+    ///     collect_commands![get_user],
+    ///     collect_commands![],
+    /// )
+    /// .namespace("users");
+    /// ```
+    ///
+    /// ```ts
+    /// queryClient.invalidateQueries({ queryKey: queryKeys.users.all });
+    /// ```
+    #[must_use]
+    pub fn namespace(mut self, namespace: impl Into<Cow<'static, str>>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Sets the first element of every generated query and mutation key.
+    ///
+    /// The root defaults to the [plugin name](CommandSet::plugin_name), or
+    /// `"app"` for the application's commands. Set it if a plugin's keys
+    /// could collide with the application's, like for a plugin named `app`.
+    #[must_use]
+    pub fn key_root(mut self, root: impl Into<Cow<'static, str>>) -> Self {
+        self.key_root = Some(root.into());
+        self
+    }
+
+    /// Sets the name of the plugin the commands belong to.
+    ///
+    /// This is forwarded to the returned [`tauri_specta::Builder`] and is the
+    /// default [root](CommandSet::key_root) of every generated query and
+    /// mutation key, so the plugin's cache entries can't collide with the
+    /// application's.
+    #[must_use]
+    pub fn plugin_name(mut self, plugin_name: &'static str) -> Self {
+        self.plugin_name = Some(plugin_name);
+        self
+    }
+
+    /// Sets the error handling mode of the generated commands.
+    ///
+    /// [`CommandSet::build`] renders the helpers for this mode and sets the
+    /// returned [`tauri_specta::Builder`] to it, so the helpers always match the
+    /// generated commands. Set it here rather than on the builder. The default
+    /// is [`ErrorHandlingMode::Result`].
+    ///
+    /// The generated `queryFn` and `mutationFn` of commands which return a
+    /// [`Result`] unwrap the mode's result shape, so failed commands reject
+    /// with their typed error. The error type is passed to TanStack Query as
+    /// `TError`. In [`ErrorHandlingMode::Throw`] commands already reject with a
+    /// `CommandError`, so they're called as is.
+    #[must_use]
+    pub fn error_handling(mut self, error_handling: ErrorHandlingMode) -> Self {
+        self.error_handling = error_handling;
+        self
+    }
+
     /// Combines two command sets.
     ///
     /// Commands and types from both sets are retained. Events and constants
     /// from `other` replace entries with the same name from `self`. The
-    /// function casing configured on `self` is retained for the merged set.
+    /// function casing, error handling mode, key root and plugin name
+    /// configured on `self` are retained for the merged set. The
+    /// [namespaces](CommandSet::namespace) of both sets are kept for their own
+    /// commands.
     #[must_use]
     pub fn merge(&self, other: &Self) -> Self {
         let handler = self.handler.clone();
@@ -253,6 +645,32 @@ impl<R: Runtime> CommandSet<R> {
         let mut constants = self.constants.clone();
         constants.extend(other.constants.clone());
 
+        let mut invalidations = self.invalidations.clone();
+        for (type_id, (event, queries)) in &other.invalidations {
+            invalidations
+                .entry(*type_id)
+                .or_insert_with(|| (event, Default::default()))
+                .1
+                .extend(queries.iter().cloned());
+        }
+
+        let mut mutation_invalidations = self.mutation_invalidations.clone();
+        for (mutation, queries) in &other.mutation_invalidations {
+            mutation_invalidations
+                .entry(mutation.clone())
+                .or_default()
+                .extend(queries.iter().cloned());
+        }
+
+        let mut namespaces = self.command_namespaces();
+        namespaces.extend(other.command_namespaces());
+
+        let mut query_options = self.query_options.clone();
+        query_options.extend(other.query_options.clone());
+
+        let mut mutation_options = self.mutation_options.clone();
+        mutation_options.extend(other.mutation_options.clone());
+
         Self {
             handler: Arc::new(move |i| {
                 (handler)(Invoke {
@@ -262,16 +680,31 @@ impl<R: Runtime> CommandSet<R> {
                 }) || (other_handler)(i)
             }),
             queries: self.queries.iter().chain(&other.queries).cloned().collect(),
+            infinite_queries: self
+                .infinite_queries
+                .iter()
+                .chain(&other.infinite_queries)
+                .cloned()
+                .collect(),
             mutations: self
                 .mutations
                 .iter()
                 .chain(&other.mutations)
                 .cloned()
                 .collect(),
+            invalidations,
+            mutation_invalidations,
+            query_options,
+            mutation_options,
             types,
             events: self.events.clone().merge(other.events.clone()),
             constants,
             function_casing: self.function_casing,
+            error_handling: self.error_handling,
+            namespace: None,
+            namespaces,
+            key_root: self.key_root.clone(),
+            plugin_name: self.plugin_name,
         }
     }
 
@@ -282,57 +715,183 @@ impl<R: Runtime> CommandSet<R> {
     /// exporting the returned builder. The builder owns the combined invoke
     /// handler and must also be supplied to Tauri with
     /// [`tauri_specta::Builder::invoke_handler`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a command passed to [`CommandSet::invalidate_on`],
+    /// [`CommandSet::invalidates`], [`CommandSet::query_options`] or
+    /// [`CommandSet::mutation_options`] isn't registered on the command set,
+    /// or if an invalidation or options don't match the command's arguments.
+    #[track_caller]
     #[must_use]
+    #[allow(clippy::panic)]
     pub fn build(self, framework: TanstackQueryFramework) -> (String, tauri_specta::Builder<R>) {
         let output = {
-            let mut output = format!("/** Tanstack Query */\n{}\n", framework.import());
+            let mut output = format!(
+                "/** Tanstack Query */\n{}\n",
+                framework.import(
+                    !self.infinite_queries.is_empty(),
+                    self.queries
+                        .iter()
+                        .chain(self.infinite_queries.iter().map(|(function, _)| function))
+                        .chain(&self.mutations)
+                        .any(|function| self.unwraps(function)),
+                )
+            );
+
+            if let Some(unwrap_impl) = self.unwrap_impl() {
+                output.push('\n');
+                output.push_str(unwrap_impl);
+                output.push('\n');
+            }
 
             if !self.queries.is_empty() {
                 output.push_str("\nexport const queries = {");
                 for function in &self.queries {
                     let name = self.function_casing.apply(&function.name).into_owned();
-                    let name_json =
-                        serde_json::to_string(&name).expect("failed to serialize query name");
                     let property = render_property_name(&name);
                     let command = render_member_access("commands", &name);
+                    let key_prefix = self.render_key_prefix(function);
+                    let query_key = if function.args.is_empty() {
+                        format!("[{key_prefix}]")
+                    } else {
+                        // Arguments are keyed by name so partial keys can match them.
+                        let args = function
+                            .args
+                            .iter()
+                            .enumerate()
+                            .map(|(idx, (arg, _))| {
+                                format!("{}: args[{idx}]", arg.to_lower_camel_case())
+                            })
+                            .collect::<Vec<_>>();
+                        format!("[{key_prefix}, {{ {} }}]", args.join(", "))
+                    };
 
-                    let options = format!(
-                        "{{ queryKey: [{name_json}, ...args], queryFn: () => {command}(...args) }}"
-                    );
+                    let query_fn = self.render_call(function, format!("{command}(...args)"));
+                    let defaults =
+                        self.render_query_options(function, |idx, _| Some(format!("args[{idx}]")));
+                    let options =
+                        format!("{{ queryKey: {query_key}, queryFn: () => {query_fn}{defaults} }}");
+                    let generics = self
+                        .render_result_types(function, &command)
+                        .map(|(data, error)| format!("<{data}, {error}>"))
+                        .unwrap_or_default();
 
                     output.push_str(&format!(
                         "\n\t{property}: (...args: Parameters<typeof {command}>) => {},",
-                        framework.query_options(options)
+                        framework.query_options(&generics, options)
                     ));
                 }
                 if !self.queries.is_empty() {
                     output.push('\n');
                 }
                 output.push_str("};");
+            }
 
-                output.push_str("\nexport const queryKeys = {");
-                for function in &self.queries {
+            if !self.infinite_queries.is_empty() {
+                output.push_str("\nexport const infiniteQueries = {");
+                for (function, pagination) in &self.infinite_queries {
                     let name = self.function_casing.apply(&function.name).into_owned();
-                    let name_json =
-                        serde_json::to_string(&name).expect("failed to serialize query name");
                     let property = render_property_name(&name);
                     let command = render_member_access("commands", &name);
+                    let key_prefix = self.render_key_prefix(function);
+                    let cursor = function
+                        .args
+                        .iter()
+                        .position(|(arg, _)| *arg == pagination.cursor_argument)
+                        .expect("infinite query commands are validated to have a cursor argument");
+
+                    let input = query_input(function, Some(cursor), &command);
+                    let call_args = function
+                        .args
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, (arg, _))| {
+                            if idx == cursor {
+                                "pageParam".to_string()
+                            } else {
+                                format!("input.{}", arg.to_lower_camel_case())
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let (params, query_key) = match &input {
+                        Some(input) => {
+                            (format!("input: {input}"), format!("[{key_prefix}, input]"))
+                        }
+                        None => (String::new(), format!("[{key_prefix}]")),
+                    };
+                    let initial_cursor = serde_json::to_string(&pagination.initial_cursor)
+                        .expect("failed to serialize initial cursor");
+                    let next_cursor =
+                        render_member_access("lastPage", &pagination.next_cursor_field);
+
+                    let query_fn = self.render_call(function, format!("{command}({call_args})"));
+                    let defaults = self.render_query_options(function, |idx, arg| {
+                        (idx != cursor).then(|| format!("input.{}", arg.to_lower_camel_case()))
+                    });
+                    let options = format!(
+                        "{{ queryKey: {query_key}, queryFn: ({{ pageParam }}) => {query_fn}, initialPageParam: {initial_cursor} as Parameters<typeof {command}>[{cursor}], getNextPageParam: (lastPage) => {next_cursor}{defaults} }}"
+                    );
+                    // `TPageParam` can't be inferred once the other generics are given.
+                    let generics = self
+                        .render_result_types(function, &command)
+                        .map(|(data, error)| {
+                            let page_param = format!("Parameters<typeof {command}>[{cursor}]");
+                            format!(
+                                "<{data}, {error}, {{ pages: {data}[]; pageParams: {page_param}[] }}, readonly unknown[], {page_param}>"
+                            )
+                        })
+                        .unwrap_or_default();
 
                     output.push_str(&format!(
-                        "\n\t{property}: (...args: Partial<Parameters<typeof {command}>>) => [{name_json}, ...args],"
+                        "\n\t{property}: ({params}) => {},",
+                        framework.infinite_query_options(&generics, options)
                     ));
                 }
                 output.push_str("\n};");
             }
 
+            if !self.queries.is_empty() || !self.infinite_queries.is_empty() {
+                let keys = self
+                    .queries
+                    .iter()
+                    .map(|function| (function, None))
+                    .chain(
+                        self.infinite_queries
+                            .iter()
+                            .map(|(function, pagination)| (function, Some(pagination))),
+                    )
+                    .map(|(function, pagination)| {
+                        let name = self.function_casing.apply(&function.name);
+                        let command = render_member_access("commands", &name);
+                        let key_prefix = self.render_key_prefix(function);
+                        let cursor = pagination.and_then(|pagination| {
+                            function
+                                .args
+                                .iter()
+                                .position(|(arg, _)| *arg == pagination.cursor_argument)
+                        });
+
+                        let key = match query_input(function, cursor, &command) {
+                            Some(input) => format!(
+                                "(input?: Partial<{input}>) => input === undefined ? [{key_prefix}] : [{key_prefix}, input]"
+                            ),
+                            None => format!("() => [{key_prefix}]"),
+                        };
+                        (function, key)
+                    })
+                    .collect();
+                output.push_str(&self.render_keys("queryKeys", keys));
+            }
+
             if !self.mutations.is_empty() {
                 output.push_str("\nexport const mutations = {");
                 for function in &self.mutations {
                     let name = self.function_casing.apply(&function.name).into_owned();
-                    let name_json =
-                        serde_json::to_string(&name).expect("failed to serialize mutation name");
                     let property = render_property_name(&name);
                     let command = render_member_access("commands", &name);
+                    let key_prefix = self.render_key_prefix(function);
                     let args = function
                         .args
                         .iter()
@@ -349,25 +908,72 @@ impl<R: Runtime> CommandSet<R> {
                         .collect::<Vec<_>>();
 
                     let mutation_fn = if args.is_empty() {
-                        format!("() => {command}()")
+                        format!(
+                            "() => {}",
+                            self.render_call(function, format!("{command}()"))
+                        )
                     } else {
                         format!(
-                            "(input: {{ {} }}) => {command}({})",
+                            "(input: {{ {} }}) => {}",
                             args.join("; "),
-                            arg_names
-                                .iter()
-                                .map(|arg| format!("input.{arg}"))
-                                .collect::<Vec<_>>()
-                                .join(", ")
+                            self.render_call(
+                                function,
+                                format!(
+                                    "{command}({})",
+                                    arg_names
+                                        .iter()
+                                        .map(|arg| format!("input.{arg}"))
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                )
+                            )
                         )
                     };
+                    let generics = self
+                        .render_result_types(function, &command)
+                        .map(|(data, error)| {
+                            let variables = if args.is_empty() {
+                                "void".to_string()
+                            } else {
+                                format!("{{ {} }}", args.join("; "))
+                            };
+                            format!("<{data}, {error}, {variables}>")
+                        })
+                        .unwrap_or_default();
 
-                    let options =
-                        format!("{{ mutationKey: [{name_json}], mutationFn: {mutation_fn} }}");
+                    let defaults = self
+                        .mutation_options
+                        .get(&function.name)
+                        .map(MutationOptions::render)
+                        .unwrap_or_default();
+                    let (params, options) = if self
+                        .mutation_invalidations
+                        .contains_key(&function.name)
+                    {
+                        let invalidations = render_member_access("mutationInvalidations", &name);
+                        let on_success = if args.is_empty() {
+                            format!("() => Promise.all({invalidations}()")
+                        } else {
+                            format!("(_data, input) => Promise.all({invalidations}(input)")
+                        };
+                        (
+                            format!("queryClient?: {QUERY_CLIENT_TYPE}"),
+                            format!(
+                                "{{ mutationKey: [{key_prefix}], mutationFn: {mutation_fn}, meta: {{ invalidates: {invalidations} }}, onSuccess: {on_success}.map((queryKey) => queryClient?.invalidateQueries({{ queryKey }}))){defaults} }}"
+                            ),
+                        )
+                    } else {
+                        (
+                            String::new(),
+                            format!(
+                                "{{ mutationKey: [{key_prefix}], mutationFn: {mutation_fn}{defaults} }}"
+                            ),
+                        )
+                    };
 
                     output.push_str(&format!(
-                        "\n\t{property}: () => {},",
-                        framework.mutation_options(options)
+                        "\n\t{property}: ({params}) => {},",
+                        framework.mutation_options(&generics, options)
                     ));
                 }
                 if !self.mutations.is_empty() {
@@ -375,14 +981,75 @@ impl<R: Runtime> CommandSet<R> {
                 }
                 output.push_str("};");
 
-                output.push_str("\nexport const mutationKeys = {");
+                let keys = self
+                    .mutations
+                    .iter()
+                    .map(|function| {
+                        let key = format!("() => [{}]", self.render_key_prefix(function));
+                        (function, key)
+                    })
+                    .collect();
+                output.push_str(&self.render_keys("mutationKeys", keys));
+            }
+
+            for query in self.query_options.keys() {
+                assert!(
+                    self.queries
+                        .iter()
+                        .chain(self.infinite_queries.iter().map(|(function, _)| function))
+                        .any(|function| function.name == *query),
+                    "Query '{query}' with options is not registered on the command set"
+                );
+            }
+            for mutation in self.mutation_options.keys() {
+                assert!(
+                    self.mutations
+                        .iter()
+                        .any(|function| function.name == *mutation),
+                    "Mutation '{mutation}' with options is not registered on the command set"
+                );
+            }
+            for mutation in self.mutation_invalidations.keys() {
+                assert!(
+                    self.mutations
+                        .iter()
+                        .any(|function| function.name == *mutation),
+                    "Mutation '{mutation}' which invalidates queries is not registered on the command set"
+                );
+            }
+            if !self.mutation_invalidations.is_empty() {
+                output.push_str("\nexport const mutationInvalidations = {");
                 for function in &self.mutations {
+                    let Some(invalidations) = self.mutation_invalidations.get(&function.name)
+                    else {
+                        continue;
+                    };
                     let name = self.function_casing.apply(&function.name).into_owned();
-                    let name_json =
-                        serde_json::to_string(&name).expect("failed to serialize mutation name");
                     let property = render_property_name(&name);
+                    let command = render_member_access("commands", &name);
+                    let args = function
+                        .args
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, (arg, _))| {
+                            let arg = arg.to_lower_camel_case();
+                            format!("{arg}: Parameters<typeof {command}>[{idx}]")
+                        })
+                        .collect::<Vec<_>>();
+                    let params = if args.is_empty() {
+                        String::new()
+                    } else {
+                        format!("input: {{ {} }}", args.join("; "))
+                    };
+                    let query_keys = invalidations
+                        .iter()
+                        .map(|invalidation| {
+                            self.render_invalidated_query_key(invalidation, function)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
 
-                    output.push_str(&format!("\n\t{property}: () => [{name_json}],"));
+                    output.push_str(&format!("\n\t{property}: ({params}) => [{query_keys}],"));
                 }
                 output.push_str("\n};");
             }
@@ -391,10 +1058,45 @@ impl<R: Runtime> CommandSet<R> {
                 output.push('\n');
             }
 
+            if !self.invalidations.is_empty() {
+                output.push_str(&format!(
+                    "\nexport function subscribeInvalidations(queryClient: {QUERY_CLIENT_TYPE}) {{"
+                ));
+                output.push_str("\n\tconst unlisten = [");
+                for (event, queries) in self.resolve_invalidating_events() {
+                    output.push_str(&format!("\n\t\t{event}.listen(() => {{"));
+                    for query in queries {
+                        let function = self
+                            .queries
+                            .iter()
+                            .chain(self.infinite_queries.iter().map(|(function, _)| function))
+                            .find(|function| function.name == *query)
+                            .unwrap_or_else(|| {
+                                panic!(
+                                    "Query '{query}' invalidated by an event is not registered on the command set"
+                                )
+                            });
+                        let query_key = self.render_key_accessor("queryKeys", function);
+                        output.push_str(&format!(
+                            "\n\t\t\tqueryClient.invalidateQueries({{ queryKey: {query_key}() }});"
+                        ));
+                    }
+                    output.push_str("\n\t\t}),");
+                }
+                output.push_str("\n\t];");
+                output.push_str("\n\treturn () => {\n\t\tfor (const promise of unlisten) promise.then((unlisten) => unlisten());\n\t};");
+                output.push_str("\n}\n");
+            }
+
             output
         };
 
         let mut commands = self.queries;
+        commands.extend(
+            self.infinite_queries
+                .into_iter()
+                .map(|(function, _)| function),
+        );
         commands.extend(self.mutations);
         let types = self.types;
 
@@ -407,7 +1109,11 @@ impl<R: Runtime> CommandSet<R> {
                 }),
             ))
             .events(self.events)
-            .function_casing(self.function_casing);
+            .function_casing(self.function_casing)
+            .error_handling(self.error_handling);
+        if let Some(plugin_name) = self.plugin_name {
+            builder = builder.plugin_name(plugin_name);
+        }
 
         for (k, v) in self.constants {
             builder = builder.constant(k, v);
@@ -417,6 +1123,603 @@ impl<R: Runtime> CommandSet<R> {
     }
 }
 
+impl<R: Runtime> CommandSet<R> {
+    /// The generated `events` members which invalidate queries, with the queries they invalidate.
+    ///
+    /// Events are looked up by type, so renamed events and the events of a group resolve to the name they're registered with.
+    #[allow(clippy::panic)]
+    fn resolve_invalidating_events(&self) -> BTreeMap<String, BTreeSet<Cow<'static, str>>> {
+        let events = self.events.definitions(&mut Types::default());
+        let mut invalidations = BTreeMap::<_, BTreeSet<_>>::new();
+        for (type_id, (event, queries)) in &self.invalidations {
+            let mut registered = events
+                .iter()
+                .filter(|(_, definition)| definition.type_id == *type_id)
+                .peekable();
+            if registered.peek().is_none() {
+                panic!(
+                    "Event '{event}' which invalidates queries is not registered on the command set"
+                );
+            }
+            for (name, definition) in registered {
+                if definition.direction == EventDirection::ToBackend {
+                    panic!(
+                        "Event '{event}' which invalidates queries can't be listened to in the frontend"
+                    );
+                }
+                let member = match definition.group {
+                    // Events in a group are nested in an object by the name of the event without the group's prefix.
+                    Some(group) => render_member_access(
+                        &render_member_access("events", &self.function_casing.apply(group)),
+                        &self.function_casing.apply(&name[group.len() + 1..]),
+                    ),
+                    None => render_member_access("events", &self.function_casing.apply(name)),
+                };
+                invalidations
+                    .entry(member)
+                    .or_default()
+                    .extend(queries.iter().cloned());
+            }
+        }
+        invalidations
+    }
+
+    /// Whether the generated helpers unwrap the result of the command.
+    ///
+    /// Effect and neverthrow wrap every command, including those which can't fail.
+    fn unwraps(&self, function: &datatype::Function) -> bool {
+        match self.error_handling {
+            ErrorHandlingMode::Throw => false,
+            ErrorHandlingMode::Effect | ErrorHandlingMode::Neverthrow => true,
+            ErrorHandlingMode::Result | ErrorHandlingMode::DataError => {
+                returns_result(function, &self.types)
+            }
+        }
+    }
+
+    /// The runtime which unwraps the results of commands in the configured error handling mode, if it's used.
+    fn unwrap_impl(&self) -> Option<&'static str> {
+        let used = self
+            .queries
+            .iter()
+            .chain(self.infinite_queries.iter().map(|(function, _)| function))
+            .chain(&self.mutations)
+            .any(|function| self.unwraps(function));
+        if !used {
+            return None;
+        }
+
+        match self.error_handling {
+            ErrorHandlingMode::Throw => None,
+            ErrorHandlingMode::Result => Some(UNWRAP_RESULT_IMPL),
+            ErrorHandlingMode::DataError => Some(UNWRAP_DATA_ERROR_IMPL),
+            ErrorHandlingMode::Effect => Some(UNWRAP_EFFECT_IMPL),
+            ErrorHandlingMode::Neverthrow => Some(UNWRAP_NEVERTHROW_IMPL),
+        }
+    }
+
+    /// Renders a call of the command which resolves to its data and rejects with its error.
+    fn render_call(&self, function: &datatype::Function, call: String) -> String {
+        if self.unwraps(function) {
+            format!("unwrapQueryCommand({call})")
+        } else {
+            call
+        }
+    }
+
+    /// Renders the data and error types of a command whose result is unwrapped.
+    fn render_result_types(
+        &self,
+        function: &datatype::Function,
+        command: &str,
+    ) -> Option<(String, String)> {
+        self.unwraps(function).then(|| {
+            (
+                format!("QueryCommandData<typeof {command}>"),
+                format!("QueryCommandError<typeof {command}>"),
+            )
+        })
+    }
+
+    /// Renders the default options of a query as properties to append to its options object.
+    ///
+    /// `access` renders how the argument at the index is accessed in the options, if it can be.
+    #[allow(clippy::panic)]
+    fn render_query_options(
+        &self,
+        function: &datatype::Function,
+        access: impl Fn(usize, &str) -> Option<String>,
+    ) -> String {
+        let Some(options) = self.query_options.get(&function.name) else {
+            return String::new();
+        };
+
+        let enabled = options
+            .enabled_when_set
+            .iter()
+            .map(|argument| {
+                function
+                    .args
+                    .iter()
+                    .position(|(arg, _)| arg == argument)
+                    .and_then(|idx| access(idx, argument))
+                    .map(|arg| format!("{arg} != null"))
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Query '{}' has no argument '{argument}' to enable it with",
+                            function.name
+                        )
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        let mut out = options.render();
+        if !enabled.is_empty() {
+            out.push_str(&format!(", enabled: {}", enabled.join(" && ")));
+        }
+        out
+    }
+
+    /// Renders the key of the queries a mutation invalidates, in terms of the mutation's `input`.
+    #[allow(clippy::panic)]
+    fn render_invalidated_query_key(
+        &self,
+        invalidation: &Invalidation,
+        mutation: &datatype::Function,
+    ) -> String {
+        let (query, cursor) = self
+            .queries
+            .iter()
+            .map(|function| (function, None))
+            .chain(
+                self.infinite_queries
+                    .iter()
+                    .map(|(function, pagination)| (function, Some(&pagination.cursor_argument))),
+            )
+            .find(|(function, _)| function.name == invalidation.query)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Query '{}' invalidated by mutation '{}' is not registered on the command set",
+                    invalidation.query, mutation.name
+                )
+            });
+        let query_key = self.render_key_accessor("queryKeys", query);
+
+        let args = invalidation
+            .args
+            .iter()
+            .map(|(query_argument, mutation_argument)| {
+                assert!(
+                    Some(query_argument) != cursor
+                        && query.args.iter().any(|(arg, _)| arg == query_argument),
+                    "Query '{}' has no argument '{query_argument}' to match mutation '{}' with",
+                    invalidation.query,
+                    mutation.name
+                );
+                assert!(
+                    mutation.args.iter().any(|(arg, _)| arg == mutation_argument),
+                    "Mutation '{}' has no argument '{mutation_argument}' to invalidate query '{}' with",
+                    mutation.name,
+                    invalidation.query
+                );
+                format!(
+                    "{}: input.{}",
+                    query_argument.to_lower_camel_case(),
+                    mutation_argument.to_lower_camel_case()
+                )
+            })
+            .collect::<Vec<_>>();
+        if args.is_empty() {
+            format!("{query_key}()")
+        } else {
+            format!("{query_key}({{ {} }})", args.join(", "))
+        }
+    }
+
+    /// The first element of every key.
+    fn root(&self) -> &str {
+        self.key_root
+            .as_deref()
+            .or(self.plugin_name)
+            .unwrap_or("app")
+    }
+
+    /// The namespace of the command, which is the namespace of the set if it has one.
+    fn namespace_of(&self, function: &datatype::Function) -> Option<&str> {
+        self.namespace
+            .as_deref()
+            .or_else(|| self.namespaces.get(&function.name).map(|ns| &**ns))
+    }
+
+    /// The namespaces of the commands, by their Rust name.
+    fn command_namespaces(&self) -> BTreeMap<Cow<'static, str>, Cow<'static, str>> {
+        match &self.namespace {
+            Some(namespace) => self
+                .queries
+                .iter()
+                .chain(self.infinite_queries.iter().map(|(function, _)| function))
+                .chain(&self.mutations)
+                .map(|function| (function.name.clone(), namespace.clone()))
+                .collect(),
+            None => self.namespaces.clone(),
+        }
+    }
+
+    /// Renders the elements every key of the command starts with.
+    fn render_key_prefix(&self, function: &datatype::Function) -> String {
+        let root = self.root();
+        let name = self.function_casing.apply(&function.name);
+        let prefix = match self.namespace_of(function) {
+            Some(namespace) => vec![root, namespace, &*name],
+            None => vec![root, &*name],
+        };
+
+        prefix
+            .into_iter()
+            .map(|segment| serde_json::to_string(segment).expect("failed to serialize key"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Renders the property of a command in the generated keys `object`.
+    fn render_key_accessor(&self, object: &str, function: &datatype::Function) -> String {
+        let object = match self.namespace_of(function) {
+            Some(namespace) => render_member_access(object, namespace),
+            None => object.to_string(),
+        };
+        render_member_access(&object, &self.function_casing.apply(&function.name))
+    }
+
+    /// Renders an object of keys, with the keys of namespaced commands nested in an object per namespace.
+    ///
+    /// Each level has an `all` key matching every command in it.
+    fn render_keys(&self, object: &str, keys: Vec<(&datatype::Function, String)>) -> String {
+        let root = serde_json::to_string(self.root()).expect("failed to serialize key");
+        let mut levels = BTreeMap::<Option<&str>, Vec<_>>::new();
+        for (function, key) in keys {
+            levels
+                .entry(self.namespace_of(function))
+                .or_default()
+                .push((self.function_casing.apply(&function.name), key));
+        }
+        let namespaces = levels.keys().flatten().copied().collect::<BTreeSet<_>>();
+
+        let mut out = format!("\nexport const {object} = {{\n\tall: [{root}],");
+        for (namespace, keys) in levels {
+            let indent = match namespace {
+                Some(namespace) => {
+                    assert!(
+                        namespace != "all",
+                        "The namespace 'all' collides with the key of every command"
+                    );
+                    let namespace_json =
+                        serde_json::to_string(namespace).expect("failed to serialize key");
+                    out.push_str(&format!(
+                        "\n\t{}: {{\n\t\tall: [{root}, {namespace_json}],",
+                        render_property_name(namespace)
+                    ));
+                    "\t\t"
+                }
+                None => "\t",
+            };
+            for (name, key) in keys {
+                assert!(
+                    name != "all" && (namespace.is_some() || !namespaces.contains(&*name)),
+                    "The key of command '{name}' collides with a namespace or the 'all' key"
+                );
+                out.push_str(&format!(
+                    "\n{indent}{}: {key},",
+                    render_property_name(&name)
+                ));
+            }
+            if namespace.is_some() {
+                out.push_str("\n\t},");
+            }
+        }
+        out.push_str("\n};");
+        out
+    }
+}
+
+/// Whether the command returns a [`Result`], which the bindings represent in the shape of the error handling mode.
+fn returns_result(function: &datatype::Function, types: &Types) -> bool {
+    matches!(
+        function.result(),
+        Some(DataType::Reference(Reference::Named(r)))
+            if types.get(r).is_some_and(|ndt| {
+                ndt.name == "Result"
+                    && matches!(&*ndt.module_path, "std::result" | "core::result")
+            })
+    )
+}
+
+const UNWRAP_RESULT_IMPL: &str = r#"type QueryCommandData<C extends (...args: any) => any> = Awaited<ReturnType<C>> extends infer R ? R extends { status: "ok"; data: infer T } ? T : never : never;
+type QueryCommandError<C extends (...args: any) => any> = Awaited<ReturnType<C>> extends infer R ? R extends { status: "error"; error: infer E } ? E : R extends { status: "panic" } ? Error : never : never;
+async function unwrapQueryCommand<R extends { status: "ok"; data: unknown } | { status: "error"; error: unknown } | { status: "panic"; message: string }>(result: Promise<R>): Promise<R extends { status: "ok"; data: infer T } ? T : never> {
+	const r = await result;
+	if (r.status === "ok") return r.data as any;
+	throw r.status === "error" ? r.error : new Error(r.message);
+}"#;
+
+const UNWRAP_DATA_ERROR_IMPL: &str = r#"type QueryCommandData<C extends (...args: any) => any> = Awaited<ReturnType<C>> extends infer R ? R extends { data: infer T; error: null } ? T : never : never;
+type QueryCommandError<C extends (...args: any) => any> = Awaited<ReturnType<C>> extends infer R ? R extends { data: null; error: infer E } ? E : never : never;
+async function unwrapQueryCommand<R extends { data: unknown; error: unknown }>(result: Promise<R>): Promise<R extends { data: infer T; error: null } ? T : never> {
+	const r = await result;
+	if (r.error === null) return r.data as any;
+	throw r.error;
+}"#;
+
+const UNWRAP_EFFECT_IMPL: &str = r#"type QueryCommandData<C extends (...args: any) => any> = ReturnType<C> extends Effect.Effect<infer T, any> ? T : never;
+type QueryCommandError<C extends (...args: any) => any> = ReturnType<C> extends Effect.Effect<any, infer E> ? E : never;
+function unwrapQueryCommand<T, E>(result: Effect.Effect<T, E>): Promise<T> {
+	return Effect.runPromise(Effect.either(result)).then((r) => (r._tag === "Right" ? r.right : Promise.reject(r.left)));
+}"#;
+
+const UNWRAP_NEVERTHROW_IMPL: &str = r#"type QueryCommandData<C extends (...args: any) => any> = ReturnType<C> extends ResultAsync<infer T, any> ? T : never;
+type QueryCommandError<C extends (...args: any) => any> = ReturnType<C> extends ResultAsync<any, infer E> ? E : never;
+async function unwrapQueryCommand<T, E>(result: ResultAsync<T, E>): Promise<T> {
+	const r = await result;
+	if (r.isOk()) return r.value;
+	throw r.error;
+}"#;
+
+/// The structural type of the `QueryClient` passed to the generated helpers, so they work with every framework.
+const QUERY_CLIENT_TYPE: &str =
+    "{ invalidateQueries: (filters: { queryKey: readonly unknown[] }) => unknown }";
+
+/// A query invalidated by a mutation. Pass this to [`CommandSet::invalidates`].
+///
+/// A name converts into an invalidation of every cached instance of the query.
+#[derive(Debug, Clone)]
+pub struct Invalidation {
+    query: Cow<'static, str>,
+    args: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+}
+
+impl Invalidation {
+    /// Invalidates the query command with the Rust name `query`.
+    pub fn new(query: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            query: query.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Only invalidates the instances of the query whose `query_argument` equals the mutation's `mutation_argument`.
+    ///
+    /// Both are the names of the Rust arguments. The query keys of regular
+    /// queries are positional, so their matched arguments must be a prefix of
+    /// the query's arguments. Infinite queries can be matched by any arguments
+    /// except the cursor.
+    #[must_use]
+    pub fn arg(
+        mut self,
+        query_argument: impl Into<Cow<'static, str>>,
+        mutation_argument: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.args
+            .push((query_argument.into(), mutation_argument.into()));
+        self
+    }
+}
+
+impl From<&'static str> for Invalidation {
+    fn from(query: &'static str) -> Self {
+        Self::new(query)
+    }
+}
+
+impl From<String> for Invalidation {
+    fn from(query: String) -> Self {
+        Self::new(query)
+    }
+}
+
+/// The default TanStack Query options of a query command.
+///
+/// Pass this to [`CommandSet::query_options`]. Options which aren't set use
+/// TanStack Query's defaults.
+#[derive(Debug, Clone, Default)]
+pub struct QueryOptions {
+    stale_time: Option<Duration>,
+    gc_time: Option<Duration>,
+    refetch_interval: Option<Duration>,
+    retry: Option<u32>,
+    enabled_when_set: Vec<Cow<'static, str>>,
+}
+
+impl QueryOptions {
+    /// Creates options which use TanStack Query's defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how long the data is considered fresh (`staleTime`).
+    #[must_use]
+    pub fn stale_time(mut self, stale_time: Duration) -> Self {
+        self.stale_time = Some(stale_time);
+        self
+    }
+
+    /// Sets how long unused data is kept in the cache (`gcTime`).
+    #[must_use]
+    pub fn gc_time(mut self, gc_time: Duration) -> Self {
+        self.gc_time = Some(gc_time);
+        self
+    }
+
+    /// Refetches the query at an interval while it's in use (`refetchInterval`).
+    #[must_use]
+    pub fn refetch_interval(mut self, refetch_interval: Duration) -> Self {
+        self.refetch_interval = Some(refetch_interval);
+        self
+    }
+
+    /// Sets how many times a failed query is retried (`retry`). Use `0` to disable retries.
+    #[must_use]
+    pub fn retry(mut self, retry: u32) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Only runs the query when the argument isn't `null` or `undefined` (`enabled`).
+    ///
+    /// `argument` is the name of the Rust argument. This can be called
+    /// multiple times to require several arguments.
+    #[must_use]
+    pub fn enabled_when_set(mut self, argument: impl Into<Cow<'static, str>>) -> Self {
+        self.enabled_when_set.push(argument.into());
+        self
+    }
+
+    fn render(&self) -> String {
+        let mut out = String::new();
+        if let Some(stale_time) = self.stale_time {
+            out.push_str(&format!(", staleTime: {}", stale_time.as_millis()));
+        }
+        if let Some(gc_time) = self.gc_time {
+            out.push_str(&format!(", gcTime: {}", gc_time.as_millis()));
+        }
+        if let Some(refetch_interval) = self.refetch_interval {
+            out.push_str(&format!(
+                ", refetchInterval: {}",
+                refetch_interval.as_millis()
+            ));
+        }
+        if let Some(retry) = self.retry {
+            out.push_str(&format!(", retry: {retry}"));
+        }
+        out
+    }
+}
+
+/// The default TanStack Query options of a mutation command.
+///
+/// Pass this to [`CommandSet::mutation_options`]. Options which aren't set use
+/// TanStack Query's defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MutationOptions {
+    retry: Option<u32>,
+    network_mode: Option<NetworkMode>,
+}
+
+impl MutationOptions {
+    /// Creates options which use TanStack Query's defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how many times a failed mutation is retried (`retry`).
+    #[must_use]
+    pub fn retry(mut self, retry: u32) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Sets when the mutation runs depending on the network connection (`networkMode`).
+    #[must_use]
+    pub fn network_mode(mut self, network_mode: NetworkMode) -> Self {
+        self.network_mode = Some(network_mode);
+        self
+    }
+
+    fn render(&self) -> String {
+        let mut out = String::new();
+        if let Some(retry) = self.retry {
+            out.push_str(&format!(", retry: {retry}"));
+        }
+        if let Some(network_mode) = self.network_mode {
+            out.push_str(&format!(", networkMode: \"{}\"", network_mode.as_str()));
+        }
+        out
+    }
+}
+
+/// When TanStack Query runs a mutation depending on the network connection.
+///
+/// Tauri commands don't need a network connection, so [`Always`](Self::Always)
+/// is usually what you want unless the command makes network requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkMode {
+    /// Only run while online, which is TanStack Query's default.
+    Online,
+    /// Always run, regardless of the connection.
+    Always,
+    /// Run once while offline, then pause retries until online.
+    OfflineFirst,
+}
+
+impl NetworkMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Online => "online",
+            Self::Always => "always",
+            Self::OfflineFirst => "offlineFirst",
+        }
+    }
+}
+
+/// How the pages of an infinite query are fetched.
+///
+/// Pass this to [`CommandSet::infinite_queries`].
+#[derive(Debug, Clone)]
+pub struct Pagination {
+    cursor_argument: Cow<'static, str>,
+    next_cursor_field: Cow<'static, str>,
+    initial_cursor: serde_json::Value,
+}
+
+impl Pagination {
+    /// Creates the pagination of commands which take the cursor of the page to fetch as `cursor_argument`.
+    ///
+    /// `cursor_argument` is the name of the Rust argument, and `next_cursor_field`
+    /// is the name of the result's field as it's serialized. The first page is
+    /// fetched with a `null` cursor unless [`Pagination::initial_cursor`] is used.
+    pub fn new(
+        cursor_argument: impl Into<Cow<'static, str>>,
+        next_cursor_field: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            cursor_argument: cursor_argument.into(),
+            next_cursor_field: next_cursor_field.into(),
+            initial_cursor: serde_json::Value::Null,
+        }
+    }
+
+    /// Sets the cursor the first page is fetched with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cursor` cannot be represented as a [`serde_json::Value`].
+    #[track_caller]
+    #[must_use]
+    pub fn initial_cursor<T: Serialize>(mut self, cursor: T) -> Self {
+        self.initial_cursor =
+            serde_json::to_value(cursor).expect("Tauri Specta failed to serialize cursor");
+        self
+    }
+}
+
+/// The type of the object a query's arguments (except an infinite query's cursor) are keyed by.
+fn query_input(
+    function: &datatype::Function,
+    cursor: Option<usize>,
+    command: &str,
+) -> Option<String> {
+    let args = function
+        .args
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| Some(*idx) != cursor)
+        .map(|(idx, (arg, _))| {
+            let arg = arg.to_lower_camel_case();
+            format!("{arg}: Parameters<typeof {command}>[{idx}]")
+        })
+        .collect::<Vec<_>>();
+
+    (!args.is_empty()).then(|| format!("{{ {} }}", args.join("; ")))
+}
+
 fn is_javascript_identifier(name: &str) -> bool {
     let mut chars = name.chars();
     chars
@@ -456,52 +1759,91 @@ pub enum TanstackQueryFramework {
     Vue,
     /// Angular, using `@tanstack/angular-query-experimental`.
     Angular,
-    /// Svelte, emitting option-producing functions without an import.
+    /// Svelte, emitting option-producing functions typed with `@tanstack/svelte-query`.
     Svelte,
     /// Preact, using `@tanstack/preact-query`.
     Preact,
 }
 
 impl TanstackQueryFramework {
-    fn import(self) -> &'static str {
-        match self {
-            Self::React => "import { mutationOptions, queryOptions } from '@tanstack/react-query';",
-            Self::Solid => "import { mutationOptions, queryOptions } from '@tanstack/solid-query';",
-            Self::Vue => "import { mutationOptions, queryOptions } from '@tanstack/vue-query';",
-            Self::Angular => {
-                "import { mutationOptions, queryOptions } from '@tanstack/angular-query-experimental';"
-            }
-            Self::Svelte => "",
-            Self::Preact => {
-                "import { mutationOptions, queryOptions } from '@tanstack/preact-query';"
+    /// `typed` is whether any option types are annotated, which Svelte only needs then.
+    fn import(self, infinite_queries: bool, typed: bool) -> String {
+        let package = match self {
+            Self::React => "@tanstack/react-query",
+            Self::Solid => "@tanstack/solid-query",
+            Self::Vue => "@tanstack/vue-query",
+            Self::Angular => "@tanstack/angular-query-experimental",
+            Self::Svelte if !typed => return String::new(),
+            Self::Svelte => {
+                let infinite_query_options = if infinite_queries {
+                    "CreateInfiniteQueryOptions, "
+                } else {
+                    ""
+                };
+                return format!(
+                    "import type {{ {infinite_query_options}CreateMutationOptions, CreateQueryOptions }} from '@tanstack/svelte-query';"
+                );
             }
+            Self::Preact => "@tanstack/preact-query",
+        };
+        let infinite_query_options = if infinite_queries {
+            "infiniteQueryOptions, "
+        } else {
+            ""
+        };
+        format!(
+            "import {{ {infinite_query_options}mutationOptions, queryOptions }} from '{package}';"
+        )
+    }
+
+    fn query_options(self, generics: &str, options: String) -> String {
+        match self {
+            Self::Svelte => svelte_options("CreateQueryOptions", generics, options),
+            _ => format!("queryOptions{generics}({options})"),
         }
     }
 
-    fn query_options(self, options: String) -> String {
+    fn infinite_query_options(self, generics: &str, options: String) -> String {
         match self {
-            Self::Svelte => format!("() => ({options})"),
-            _ => format!("queryOptions({options})"),
+            Self::Svelte => svelte_options("CreateInfiniteQueryOptions", generics, options),
+            _ => format!("infiniteQueryOptions{generics}({options})"),
         }
     }
 
-    fn mutation_options(self, options: String) -> String {
+    fn mutation_options(self, generics: &str, options: String) -> String {
         match self {
-            Self::Svelte => format!("() => ({options})"),
-            _ => format!("mutationOptions({options})"),
+            Self::Svelte => svelte_options("CreateMutationOptions", generics, options),
+            _ => format!("mutationOptions{generics}({options})"),
         }
     }
 }
 
+/// Renders an option-producing function for Svelte, annotated with the option type when there are generics.
+///
+/// Svelte has no options helpers which could take the generics, so `TError` is wired through the return type.
+fn svelte_options(option_type: &str, generics: &str, options: String) -> String {
+    if generics.is_empty() {
+        format!("() => ({options})")
+    } else {
+        format!("(): {option_type}{generics} => ({options})")
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::fs;
+    use std::{fs, path::Path, time::Duration};
 
     use specta_typescript::Typescript;
     use tauri::test::MockRuntime;
-    use tauri_specta::{Casing, collect_commands};
+    use tauri_specta::{
+        BuilderConfiguration, Casing, ErrorHandlingMode, Event, EventGroup, LanguageExt,
+        collect_commands, collect_events,
+    };
 
-    use super::{CommandSet, TanstackQueryFramework};
+    use super::{
+        CommandSet, Invalidation, MutationOptions, NetworkMode, Pagination, QueryOptions,
+        TanstackQueryFramework,
+    };
 
     #[tauri::command]
     #[specta::specta]
@@ -515,16 +1857,63 @@ mod tests {
         let _ = (user_id, display_name);
     }
 
+    #[derive(serde::Serialize, specta::Type)]
+    struct UserPage {
+        users: Vec<String>,
+        next_cursor: Option<String>,
+    }
+
+    #[tauri::command]
+    #[specta::specta]
+    fn list_users(team_id: u32, cursor: Option<String>) -> UserPage {
+        let _ = (team_id, cursor);
+        UserPage {
+            users: vec![],
+            next_cursor: None,
+        }
+    }
+
+    #[tauri::command]
+    #[specta::specta]
+    fn find_user(user_id: u32) -> Result<String, String> {
+        Err(format!("user-{user_id} not found"))
+    }
+
+    #[tauri::command]
+    #[specta::specta]
+    fn delete_user(user_id: u32) -> Result<(), String> {
+        let _ = user_id;
+        Ok(())
+    }
+
+    #[derive(Clone, serde::Serialize, serde::Deserialize, specta::Type, Event)]
+    struct UserUpdated {
+        user_id: u32,
+    }
+
+    #[derive(EventGroup)]
+    enum Session {
+        SignedIn(u32),
+        SignedOut,
+    }
+
     fn export(casing: Casing) -> String {
-        let (query_bindings, builder) = CommandSet::<MockRuntime>::new(
+        let commands = CommandSet::<MockRuntime>::new(
             collect_commands![get_user],
             collect_commands![rename_user],
         )
-        .function_casing(casing)
-        .build(TanstackQueryFramework::React);
+        .function_casing(casing);
+        export_command_set(commands, TanstackQueryFramework::React)
+    }
+
+    fn export_command_set(
+        commands: CommandSet<MockRuntime>,
+        framework: TanstackQueryFramework,
+    ) -> String {
+        let (query_bindings, builder) = commands.build(framework);
 
         let dir = std::env::temp_dir().join(format!(
-            "tauri_specta_query_casing_{}_{:?}",
+            "tauri_specta_query_casing_{}_{:?}_{framework:?}",
             std::process::id(),
             std::thread::current().id()
         ));
@@ -559,4 +1948,294 @@ mod tests {
         assert!(output.contains(r#""rename-user": () => mutationOptions"#));
         assert!(output.contains(r#"commands["rename-user"](input.userId, input.displayName)"#));
     }
+
+    #[test]
+    fn infinite_queries_paginate_with_the_cursor_argument() {
+        let commands = |framework| {
+            export_command_set(
+                CommandSet::<MockRuntime>::new(collect_commands![], collect_commands![])
+                    .infinite_queries(
+                        collect_commands![list_users],
+                        Pagination::new("cursor", "next_cursor"),
+                    ),
+                framework,
+            )
+        };
+        let output = commands(TanstackQueryFramework::React);
+
+        assert!(output.contains(
+            "import { infiniteQueryOptions, mutationOptions, queryOptions } from '@tanstack/react-query';"
+        ));
+        assert!(output.contains(
+            "listUsers: (input: { teamId: Parameters<typeof commands.listUsers>[0] }) => infiniteQueryOptions("
+        ));
+        assert!(output.contains(
+            r#"queryKey: ["app", "listUsers", input], queryFn: ({ pageParam }) => commands.listUsers(input.teamId, pageParam)"#
+        ));
+        assert!(
+            output.contains("initialPageParam: null as Parameters<typeof commands.listUsers>[1]")
+        );
+        assert!(output.contains("getNextPageParam: (lastPage) => lastPage.next_cursor"));
+        assert!(output.contains(
+            r#"listUsers: (input?: Partial<{ teamId: Parameters<typeof commands.listUsers>[0] }>) => input === undefined ? ["app", "listUsers"] : ["app", "listUsers", input],"#
+        ));
+
+        let svelte = commands(TanstackQueryFramework::Svelte);
+        assert!(svelte.contains("listUsers: (input: { teamId: Parameters<typeof commands.listUsers>[0] }) => () => ({ queryKey"));
+    }
+
+    #[test]
+    fn events_invalidate_queries() {
+        let commands =
+            CommandSet::<MockRuntime>::new(collect_commands![get_user], collect_commands![])
+                .infinite_queries(
+                    collect_commands![list_users],
+                    Pagination::new("cursor", "next_cursor"),
+                )
+                .events(collect_events![UserUpdated as "user-changed", Session])
+                .invalidate_on::<UserUpdated>(collect_commands![get_user, list_users])
+                .invalidate_on::<Session>(collect_commands![get_user]);
+        let output = export_command_set(commands, TanstackQueryFramework::React);
+
+        assert!(output.contains("export function subscribeInvalidations(queryClient: "));
+        // Events are listened to by the name they're registered with.
+        assert!(output.contains(
+            "\t\tevents.userChanged.listen(() => {\n\t\t\tqueryClient.invalidateQueries({ queryKey: queryKeys.getUser() });\n\t\t\tqueryClient.invalidateQueries({ queryKey: queryKeys.listUsers() });\n\t\t}),"
+        ));
+        assert!(output.contains(
+            "\t\tevents.session.signedIn.listen(() => {\n\t\t\tqueryClient.invalidateQueries({ queryKey: queryKeys.getUser() });\n\t\t}),"
+        ));
+        assert!(output.contains("\t\tevents.session.signedOut.listen(() => {"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Query 'rename_user' invalidated by an event is not registered")]
+    fn invalidating_unknown_queries_panics() {
+        let _ = CommandSet::<MockRuntime>::new(
+            collect_commands![get_user],
+            collect_commands![rename_user],
+        )
+        .events(collect_events![UserUpdated])
+        .invalidate_on::<UserUpdated>(collect_commands![rename_user])
+        .build(TanstackQueryFramework::React);
+    }
+
+    #[test]
+    #[should_panic(expected = "UserUpdated' which invalidates queries is not registered")]
+    fn invalidating_on_unregistered_events_panics() {
+        let _ = CommandSet::<MockRuntime>::new(collect_commands![get_user], collect_commands![])
+            .invalidate_on::<UserUpdated>(collect_commands![get_user])
+            .build(TanstackQueryFramework::React);
+    }
+
+    #[test]
+    fn mutations_invalidate_declared_queries() {
+        let commands = CommandSet::<MockRuntime>::new(
+            collect_commands![get_user],
+            collect_commands![rename_user],
+        )
+        .infinite_queries(
+            collect_commands![list_users],
+            Pagination::new("cursor", "next_cursor"),
+        )
+        .invalidates(
+            "rename_user",
+            [
+                Invalidation::new("get_user").arg("user_id", "user_id"),
+                Invalidation::new("list_users"),
+            ],
+        );
+        let output = export_command_set(commands, TanstackQueryFramework::React);
+
+        assert!(output.contains(
+            "renameUser: (queryClient?: { invalidateQueries: (filters: { queryKey: readonly unknown[] }) => unknown }) => mutationOptions("
+        ));
+        assert!(output.contains(
+            "meta: { invalidates: mutationInvalidations.renameUser }, onSuccess: (_data, input) => Promise.all(mutationInvalidations.renameUser(input).map((queryKey) => queryClient?.invalidateQueries({ queryKey })))"
+        ));
+        assert!(
+            output.contains(
+                "=> [queryKeys.getUser({ userId: input.userId }), queryKeys.listUsers()],"
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Query 'get_user' has no argument 'display_name'")]
+    fn invalidating_queries_by_unknown_arguments_panics() {
+        let _ = CommandSet::<MockRuntime>::new(
+            collect_commands![get_user],
+            collect_commands![rename_user],
+        )
+        .invalidates(
+            "rename_user",
+            [Invalidation::new("get_user").arg("display_name", "display_name")],
+        )
+        .build(TanstackQueryFramework::React);
+    }
+
+    #[test]
+    fn results_are_unwrapped_in_the_error_handling_mode() {
+        let commands = CommandSet::<MockRuntime>::new(
+            collect_commands![get_user, find_user],
+            collect_commands![delete_user],
+        );
+        let output = export_command_set(commands, TanstackQueryFramework::React);
+
+        assert!(output.contains("async function unwrapQueryCommand<"));
+        assert!(output.contains(
+            "findUser: (...args: Parameters<typeof commands.findUser>) => queryOptions<QueryCommandData<typeof commands.findUser>, QueryCommandError<typeof commands.findUser>>({ queryKey: [\"app\", \"findUser\", { userId: args[0] }], queryFn: () => unwrapQueryCommand(commands.findUser(...args)) })"
+        ));
+        assert!(output.contains("queryFn: () => commands.getUser(...args)"));
+        assert!(output.contains(
+            "mutationOptions<QueryCommandData<typeof commands.deleteUser>, QueryCommandError<typeof commands.deleteUser>, { userId: Parameters<typeof commands.deleteUser>[0] }>"
+        ));
+        assert!(output.contains(
+            "mutationFn: (input: { userId: Parameters<typeof commands.deleteUser>[0] }) => unwrapQueryCommand(commands.deleteUser(input.userId))"
+        ));
+
+        // Svelte has no options helpers, so the types are given by the return type.
+        let commands = CommandSet::<MockRuntime>::new(
+            collect_commands![get_user, find_user],
+            collect_commands![delete_user],
+        );
+        let svelte = export_command_set(commands, TanstackQueryFramework::Svelte);
+
+        assert!(svelte.contains(
+            "import type { CreateMutationOptions, CreateQueryOptions } from '@tanstack/svelte-query';"
+        ));
+        assert!(svelte.contains(
+            "findUser: (...args: Parameters<typeof commands.findUser>) => (): CreateQueryOptions<QueryCommandData<typeof commands.findUser>, QueryCommandError<typeof commands.findUser>> => ({ queryKey"
+        ));
+        assert!(svelte.contains(
+            "getUser: (...args: Parameters<typeof commands.getUser>) => () => ({ queryKey"
+        ));
+        assert!(svelte.contains(
+            "deleteUser: () => (): CreateMutationOptions<QueryCommandData<typeof commands.deleteUser>, QueryCommandError<typeof commands.deleteUser>, { userId: Parameters<typeof commands.deleteUser>[0] }> => ({ mutationKey"
+        ));
+
+        let commands =
+            CommandSet::<MockRuntime>::new(collect_commands![find_user], collect_commands![])
+                .error_handling(ErrorHandlingMode::Throw);
+        let output = export_command_set(commands, TanstackQueryFramework::React);
+
+        assert!(!output.contains("unwrapQueryCommand"));
+        assert!(output.contains("queryFn: () => commands.findUser(...args)"));
+    }
+
+    #[test]
+    fn returned_builder_uses_the_error_handling_mode_of_the_helpers() {
+        struct Inspect(ErrorHandlingMode);
+
+        impl LanguageExt for Inspect {
+            type Error = std::io::Error;
+
+            fn export(self, cfg: &BuilderConfiguration, _: &Path) -> Result<(), Self::Error> {
+                assert_eq!(cfg.error_handling, self.0);
+                Ok(())
+            }
+        }
+
+        for mode in [
+            ErrorHandlingMode::Throw,
+            ErrorHandlingMode::Result,
+            ErrorHandlingMode::DataError,
+            ErrorHandlingMode::Effect,
+            ErrorHandlingMode::Neverthrow,
+        ] {
+            let (_, builder) =
+                CommandSet::<MockRuntime>::new(collect_commands![find_user], collect_commands![])
+                    .error_handling(mode)
+                    .build(TanstackQueryFramework::React);
+
+            builder
+                .export(Inspect(mode), "")
+                .expect("failed to inspect configuration");
+        }
+    }
+
+    #[test]
+    fn command_options_are_merged_into_the_generated_options() {
+        let commands = CommandSet::<MockRuntime>::new(
+            collect_commands![get_user],
+            collect_commands![rename_user],
+        )
+        .infinite_queries(
+            collect_commands![list_users],
+            Pagination::new("cursor", "next_cursor"),
+        )
+        .query_options(
+            "get_user",
+            QueryOptions::new()
+                .stale_time(Duration::from_secs(30))
+                .gc_time(Duration::from_secs(300))
+                .refetch_interval(Duration::from_millis(1500))
+                .retry(2)
+                .enabled_when_set("user_id"),
+        )
+        .query_options(
+            "list_users",
+            QueryOptions::new().enabled_when_set("team_id"),
+        )
+        .mutation_options(
+            "rename_user",
+            MutationOptions::new()
+                .retry(1)
+                .network_mode(NetworkMode::Always),
+        );
+        let output = export_command_set(commands, TanstackQueryFramework::React);
+
+        assert!(output.contains(
+            "queryFn: () => commands.getUser(...args), staleTime: 30000, gcTime: 300000, refetchInterval: 1500, retry: 2, enabled: args[0] != null })"
+        ));
+        assert!(output.contains(
+            "getNextPageParam: (lastPage) => lastPage.next_cursor, enabled: input.teamId != null })"
+        ));
+        assert!(output.contains(
+            "commands.renameUser(input.userId, input.displayName), retry: 1, networkMode: \"always\" })"
+        ));
+    }
+
+    #[test]
+    fn query_keys_are_hierarchical() {
+        let users = CommandSet::<MockRuntime>::new(
+            collect_commands![get_user],
+            collect_commands![rename_user],
+        )
+        .namespace("users")
+        // The namespace also applies to commands added after it.
+        .infinite_queries(
+            collect_commands![list_users],
+            Pagination::new("cursor", "next_cursor"),
+        );
+        let commands =
+            CommandSet::<MockRuntime>::new(collect_commands![find_user], collect_commands![])
+                .plugin_name("accounts")
+                .merge(&users);
+        let output = export_command_set(commands, TanstackQueryFramework::React);
+
+        assert!(output.contains(
+            r#"queryKey: ["accounts", "users", "getUser", { userId: args[0] }], queryFn"#
+        ));
+        assert!(output.contains(r#"mutationKey: ["accounts", "users", "renameUser"], mutationFn"#));
+        assert!(output.contains(r#"queryKey: ["accounts", "users", "listUsers", input], queryFn"#));
+        assert!(output.contains(
+            "export const queryKeys = {\n\tall: [\"accounts\"],\n\tfindUser: (input?: Partial<{ userId: Parameters<typeof commands.findUser>[0] }>) => input === undefined ? [\"accounts\", \"findUser\"] : [\"accounts\", \"findUser\", input],\n\tusers: {\n\t\tall: [\"accounts\", \"users\"],\n\t\tgetUser: "
+        ));
+        assert!(output.contains(
+            "export const mutationKeys = {\n\tall: [\"accounts\"],\n\tusers: {\n\t\tall: [\"accounts\", \"users\"],\n\t\trenameUser: () => [\"accounts\", \"users\", \"renameUser\"],\n\t},\n};"
+        ));
+    }
+
+    #[test]
+    fn key_root_replaces_the_default_root() {
+        let commands =
+            CommandSet::<MockRuntime>::new(collect_commands![get_user], collect_commands![])
+                .plugin_name("app")
+                .key_root("app-plugin");
+        let output = export_command_set(commands, TanstackQueryFramework::React);
+
+        assert!(output.contains(r#"queryKey: ["app-plugin", "getUser", { userId: args[0] }]"#));
+        assert!(output.contains("export const queryKeys = {\n\tall: [\"app-plugin\"],"));
+    }
 }
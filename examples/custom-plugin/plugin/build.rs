@@ -1,5 +1,14 @@
-const COMMANDS: &[&str] = &["add_numbers"];
+#[allow(dead_code)]
+#[path = "src/commands.rs"]
+mod commands;
 
 fn main() {
-    tauri_plugin::Builder::new(COMMANDS).build();
+    println!("cargo:rerun-if-changed=src/commands.rs");
+
+    // The command permissions are generated from the registered commands, so they're passed to `tauri_plugin::Builder` as an empty list.
+    commands::builder::<tauri::test::MockRuntime>()
+        .export(tauri_specta::Permissions::default(), "./permissions")
+        .expect("failed to export permissions");
+
+    tauri_plugin::Builder::new(&[]).build();
 }
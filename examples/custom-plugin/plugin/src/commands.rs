@@ -0,0 +1,25 @@
+//! The commands and events of the plugin.
+//!
+//! This is also included by `build.rs` to generate the plugin's permissions.
+
+use tauri::Runtime;
+use tauri_specta::{Event, collect_commands, collect_events};
+
+/// Adds two numbers, returning the result.
+#[tauri::command]
+#[specta::specta]
+fn add_numbers(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[derive(Clone, serde::Serialize, specta::Type, Event)]
+pub struct RandomNumber(pub i32);
+
+pub const PLUGIN_NAME: &str = "specta-example";
+
+pub fn builder<R: Runtime>() -> tauri_specta::Builder<R> {
+    tauri_specta::Builder::new()
+        .plugin_name(PLUGIN_NAME)
+        .commands(collect_commands![add_numbers])
+        .events(collect_events![RandomNumber])
+}
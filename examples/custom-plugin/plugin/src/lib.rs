@@ -2,26 +2,11 @@ use tauri::{
     Runtime,
     plugin::{Builder, TauriPlugin},
 };
-use tauri_specta::*;
+use tauri_specta::Event;
 
-/// Adds two numbers, returning the result.
-#[tauri::command]
-#[specta::specta]
-fn add_numbers(a: i32, b: i32) -> i32 {
-    a + b
-}
-
-#[derive(Clone, serde::Serialize, specta::Type, Event)]
-struct RandomNumber(i32);
+use commands::{PLUGIN_NAME, RandomNumber, builder};
 
-const PLUGIN_NAME: &str = "specta-example";
-
-fn builder<R: Runtime>() -> tauri_specta::Builder<R> {
-    tauri_specta::Builder::new()
-        .plugin_name(PLUGIN_NAME)
-        .commands(collect_commands![add_numbers])
-        .events(collect_events![RandomNumber])
-}
+mod commands;
 
 pub fn init<R: Runtime>() -> TauriPlugin<R> {
     let builder = builder();
@@ -50,7 +35,8 @@ mod test {
 
     #[test]
     fn export_types() {
-        builder::<tauri::Wry>()
+        let builder = builder::<tauri::Wry>();
+        builder
             .export(specta_typescript::Typescript::default(), "./bindings.ts")
             .expect("failed to export specta types");
     }
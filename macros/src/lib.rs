@@ -8,20 +8,44 @@ use heck::ToKebabCase;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
-    ConstParam, DeriveInput, GenericParam, Generics, Ident, LifetimeParam, LitStr, TypeParam,
-    WhereClause, parse_macro_input, parse_quote,
+    Attribute, ConstParam, DeriveInput, Expr, ExprLit, GenericParam, Generics, Ident,
+    LifetimeParam, Lit, LitStr, Meta, TypeParam, WhereClause, parse_macro_input, parse_quote,
 };
 
-use darling::FromDeriveInput;
+use darling::{FromDeriveInput, FromField, FromMeta, FromVariant, ast};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromMeta)]
+enum Direction {
+    #[darling(rename = "to_frontend")]
+    ToFrontend,
+    #[darling(rename = "to_backend")]
+    ToBackend,
+    #[darling(rename = "both")]
+    Both,
+}
 
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(tauri_specta), supports(struct_any, enum_any))]
+#[darling(
+    attributes(tauri_specta),
+    forward_attrs(doc, deprecated),
+    supports(struct_any, enum_any)
+)]
 struct EventOpts {
     ident: Ident,
     generics: Generics,
+    attrs: Vec<Attribute>,
 
     #[darling(default)]
     event_name: Option<String>,
+
+    #[darling(default)]
+    direction: Option<Direction>,
+
+    #[darling(default)]
+    sticky: bool,
+
+    #[darling(default)]
+    throttle_ms: Option<u64>,
 }
 
 #[proc_macro_derive(Event, attributes(tauri_specta))]
@@ -44,19 +68,366 @@ pub fn derive_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     };
     let name_lit = LitStr::new(&name_str_value, ident.span());
 
+    if opts.sticky && opts.direction == Some(Direction::ToBackend) {
+        return syn::Error::new(
+            ident.span(),
+            "sticky events must be emitted by the backend, so they can't have the `to_backend` direction",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let direction = opts
+        .direction
+        .map(|direction| direction_const(direction, &crate_ref));
+    let sticky = opts.sticky.then(|| {
+        quote!(
+            const STICKY: bool = true;
+        )
+    });
+    let throttle = opts.throttle_ms.map(|ms| {
+        quote!(const THROTTLE: ::core::option::Option<::core::time::Duration> = ::core::option::Option::Some(::core::time::Duration::from_millis(#ms));)
+    });
+
+    let (docs, deprecated) = match docs_and_deprecated(&opts.attrs) {
+        Ok(metadata) => metadata,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let docs = (!docs.is_empty()).then(|| quote!(const DOCS: &'static str = #docs;));
+    let deprecated = deprecated.map(|note| {
+        quote!(const DEPRECATED: ::core::option::Option<&'static str> = ::core::option::Option::Some(#note);)
+    });
+
     let bounds = generics_with_ident_and_bounds_only(generics);
     let type_args = generics_with_ident_only(generics);
     let where_bound = add_type_to_where_clause(generics);
 
     quote! {
         #[automatically_derived]
+        #[allow(deprecated)]
         impl #bounds #crate_ref::Event for #ident #type_args #where_bound {
             const NAME: &'static str = #name_lit;
+            #direction
+            #docs
+            #deprecated
+            #sticky
+            #throttle
         }
     }
     .into()
 }
 
+#[derive(Debug, FromField)]
+struct GroupField {
+    ty: syn::Type,
+}
+
+#[derive(Debug, FromVariant)]
+#[darling(attributes(tauri_specta), forward_attrs(doc, deprecated))]
+struct GroupVariant {
+    ident: Ident,
+    fields: ast::Fields<GroupField>,
+    attrs: Vec<Attribute>,
+
+    #[darling(default)]
+    event_name: Option<String>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(tauri_specta), supports(enum_newtype, enum_unit))]
+struct EventGroupOpts {
+    ident: Ident,
+    generics: Generics,
+    data: ast::Data<GroupVariant, ()>,
+
+    #[darling(default)]
+    event_name: Option<String>,
+
+    #[darling(default)]
+    direction: Option<Direction>,
+
+    #[darling(default)]
+    sticky: bool,
+}
+
+#[proc_macro_derive(EventGroup, attributes(tauri_specta))]
+pub fn derive_event_group(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let opts = match EventGroupOpts::from_derive_input(&ast) {
+        Ok(options) => options,
+        Err(e) => {
+            return e.write_errors().into();
+        }
+    };
+
+    let crate_ref = quote!(tauri_specta);
+    let ident = &opts.ident;
+    let generics = &opts.generics;
+
+    if opts.sticky {
+        return syn::Error::new(
+            ident.span(),
+            "event groups can't be sticky as each variant is a separate event, use a sticky `Event` for the state instead",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let name_lit = LitStr::new(
+        &opts
+            .event_name
+            .unwrap_or_else(|| ident.to_string().to_kebab_case()),
+        ident.span(),
+    );
+    let direction = opts
+        .direction
+        .map(|direction| direction_const(direction, &crate_ref));
+
+    let variants = opts.data.take_enum().unwrap_or_default();
+    let events = variants
+        .iter()
+        .map(|variant| {
+            LitStr::new(
+                &variant
+                    .event_name
+                    .clone()
+                    .unwrap_or_else(|| variant.ident.to_string().to_kebab_case()),
+                variant.ident.span(),
+            )
+        })
+        .collect::<Vec<_>>();
+    // Unit variants have a `null` payload.
+    let payload_tys = variants
+        .iter()
+        .map(|variant| match variant.fields.fields.first() {
+            Some(field) => {
+                let ty = &field.ty;
+                quote!(#ty)
+            }
+            None => quote!(()),
+        })
+        .collect::<Vec<_>>();
+    let patterns = variants
+        .iter()
+        .map(|variant| {
+            let variant_ident = &variant.ident;
+            if variant.fields.is_unit() {
+                quote!(Self::#variant_ident)
+            } else {
+                quote!(Self::#variant_ident(payload))
+            }
+        })
+        .collect::<Vec<_>>();
+    let wildcard_patterns = variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        if variant.fields.is_unit() {
+            quote!(Self::#variant_ident)
+        } else {
+            quote!(Self::#variant_ident(..))
+        }
+    });
+    let constructors = variants
+        .iter()
+        .map(|variant| {
+            let variant_ident = &variant.ident;
+            if variant.fields.is_unit() {
+                quote!(|()| Self::#variant_ident)
+            } else {
+                quote!(Self::#variant_ident)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let metadata = match variants
+        .iter()
+        .map(|variant| docs_and_deprecated(&variant.attrs))
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(metadata) => metadata,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let docs = metadata.iter().map(|(docs, _)| docs);
+    let deprecated = metadata.iter().map(|(_, deprecated)| match deprecated {
+        Some(note) => quote!(::core::option::Option::Some(#note)),
+        None => quote!(::core::option::Option::None),
+    });
+
+    let direction_value = opts.direction.unwrap_or(Direction::Both);
+    // We only require the payloads to be (de)serializable when the backend can emit (or listen to) the events.
+    let payload_fn = (direction_value != Direction::ToBackend).then(|| {
+        let payloads = variants.iter().map(|variant| {
+            if variant.fields.is_unit() {
+                quote!(&())
+            } else {
+                quote!(payload)
+            }
+        });
+        quote! {
+            fn __payload(&self) -> #crate_ref::internal::serde_json::Result<#crate_ref::internal::serde_json::Value> {
+                match self {
+                    #(#patterns => #crate_ref::internal::group_payload(#payloads),)*
+                }
+            }
+        }
+    });
+    let from_payload_fn = (direction_value != Direction::ToFrontend).then(|| {
+        quote! {
+            fn __from_payload(event: &str, payload: &str) -> #crate_ref::internal::serde_json::Result<Self> {
+                match event {
+                    #(#events => #crate_ref::internal::group_from_payload::<#payload_tys>(payload).map(#constructors),)*
+                    _ => #crate_ref::internal::unknown_group_event(<Self as #crate_ref::EventGroup>::NAME, event),
+                }
+            }
+        }
+    });
+
+    let bounds = generics_with_ident_and_bounds_only(generics);
+    let type_args = generics_with_ident_only(generics);
+    let where_bound = add_type_to_where_clause(generics);
+
+    quote! {
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl #bounds #crate_ref::EventGroup for #ident #type_args #where_bound {
+            const NAME: &'static str = #name_lit;
+            const EVENTS: &'static [&'static str] = &[#(#events),*];
+            #direction
+
+            fn event(&self) -> &'static str {
+                match self {
+                    #(#wildcard_patterns => #events,)*
+                }
+            }
+
+            #payload_fn
+            #from_payload_fn
+        }
+
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl #bounds #crate_ref::internal::CollectEvents for #ident #type_args #where_bound {
+            const NAME: &'static str = <Self as #crate_ref::EventGroup>::NAME;
+
+            fn register(events: &mut #crate_ref::Events, name: &'static str) {
+                #(#crate_ref::internal::register_group_event::<Self, #payload_tys>(events, name, #events, #docs, #deprecated);)*
+            }
+        }
+    }
+    .into()
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(
+    attributes(tauri_specta),
+    forward_attrs(doc, deprecated),
+    supports(struct_any, enum_any)
+)]
+struct RequestOpts {
+    ident: Ident,
+    generics: Generics,
+    attrs: Vec<Attribute>,
+
+    #[darling(default)]
+    event_name: Option<String>,
+
+    response: syn::TypePath,
+}
+
+#[proc_macro_derive(Request, attributes(tauri_specta))]
+pub fn derive_request(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let opts = match RequestOpts::from_derive_input(&ast) {
+        Ok(options) => options,
+        Err(e) => {
+            return e.write_errors().into();
+        }
+    };
+
+    let crate_ref = quote!(tauri_specta);
+    let ident = &opts.ident;
+    let generics = &opts.generics;
+    let response = &opts.response;
+
+    let name = LitStr::new(
+        &opts
+            .event_name
+            .unwrap_or_else(|| ident.to_string().to_kebab_case()),
+        ident.span(),
+    );
+
+    let docs = match docs_and_deprecated(&opts.attrs) {
+        Ok((docs, _)) => docs,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let docs = (!docs.is_empty()).then(|| quote!(const DOCS: &'static str = #docs;));
+
+    let bounds = generics_with_ident_and_bounds_only(generics);
+    let type_args = generics_with_ident_only(generics);
+    let where_bound = add_type_to_where_clause(generics);
+
+    quote! {
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl #bounds #crate_ref::Request for #ident #type_args #where_bound {
+            const NAME: &'static str = #name;
+            type Response = #response;
+            #docs
+        }
+    }
+    .into()
+}
+
+/// Extract the doc comments and the `#[deprecated]` message from the attributes.
+fn docs_and_deprecated(attrs: &[Attribute]) -> syn::Result<(String, Option<String>)> {
+    let mut docs = Vec::new();
+    let mut deprecated = None;
+    for attr in attrs {
+        if attr.path().is_ident("doc") {
+            if let Meta::NameValue(meta) = &attr.meta
+                && let Expr::Lit(ExprLit {
+                    lit: Lit::Str(doc), ..
+                }) = &meta.value
+            {
+                let doc = doc.value();
+                docs.push(doc.strip_prefix(' ').unwrap_or(&doc).to_string());
+            }
+        } else if attr.path().is_ident("deprecated") {
+            deprecated = Some(match &attr.meta {
+                Meta::Path(_) => String::new(),
+                Meta::NameValue(meta) => match &meta.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(note),
+                        ..
+                    }) => note.value(),
+                    value => return Err(syn::Error::new_spanned(value, "expected a string")),
+                },
+                Meta::List(_) => {
+                    let mut note = String::new();
+                    attr.parse_nested_meta(|meta| {
+                        let value = meta.value()?.parse::<LitStr>()?;
+                        if meta.path.is_ident("note") {
+                            note = value.value();
+                        }
+                        Ok(())
+                    })?;
+                    note
+                }
+            });
+        }
+    }
+
+    Ok((docs.join("\n").trim().to_string(), deprecated))
+}
+
+fn direction_const(direction: Direction, crate_ref: &TokenStream) -> TokenStream {
+    let variant = match direction {
+        Direction::ToFrontend => quote!(ToFrontend),
+        Direction::ToBackend => quote!(ToBackend),
+        Direction::Both => quote!(Both),
+    };
+    quote!(const DIRECTION: #crate_ref::EventDirection = #crate_ref::EventDirection::#variant;)
+}
+
 fn generics_with_ident_and_bounds_only(generics: &Generics) -> Option<TokenStream> {
     (!generics.params.is_empty())
         .then(|| {
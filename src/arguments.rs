@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+
+use heck::ToLowerCamelCase;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use specta::datatype::{DataType, Primitive};
+use tauri::ipc::InvokeBody;
+
+use crate::BuilderConfiguration;
+
+/// The error payload a command rejects with when its arguments are invalid.
+///
+/// The marker key is detected by the generated bindings and converted into an `InvalidArguments`.
+#[derive(Debug, Serialize)]
+pub(crate) struct InvalidArguments {
+    #[serde(rename = "__tauriSpectaInvalidArguments")]
+    details: Details,
+}
+
+#[derive(Debug, Serialize)]
+struct Details {
+    command: String,
+    argument: String,
+    path: Vec<PathSegment>,
+    message: String,
+}
+
+/// A segment of the location of the invalid value within an argument, like serde's path to an error.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Validates the arguments of a command against their Specta types when the invoke handler receives the command,
+/// before Tauri deserializes them.
+///
+/// This is intentionally conservative. Named types are not checked as their serde attributes can change how they are deserialized,
+/// so anything it can't rule out is left for Tauri to reject.
+#[derive(Debug)]
+pub(crate) struct ArgumentValidator {
+    commands: HashMap<String, Vec<Argument>>,
+}
+
+#[derive(Debug)]
+struct Argument {
+    /// The name of the argument in Rust, which Tauri expects when the command uses `rename_all = "snake_case"`.
+    name: String,
+    /// The name Tauri expects by default.
+    camel_case: String,
+    dt: DataType,
+}
+
+impl Argument {
+    /// The key the argument was sent with, and its value.
+    fn find<'a>(&'a self, values: &'a Map<String, Value>) -> Option<(&'a str, &'a Value)> {
+        [&self.camel_case, &self.name]
+            .into_iter()
+            .find_map(|key| values.get(key).map(|value| (key.as_str(), value)))
+    }
+}
+
+impl ArgumentValidator {
+    pub fn new(cfg: &BuilderConfiguration) -> Self {
+        Self {
+            commands: cfg
+                .commands
+                .iter()
+                .filter(|command| !command.args().is_empty())
+                .map(|command| {
+                    (
+                        command.name().to_string(),
+                        command
+                            .args()
+                            .iter()
+                            .map(|(name, dt)| Argument {
+                                name: name.to_string(),
+                                camel_case: name.to_lower_camel_case(),
+                                dt: dt.clone(),
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn validate(&self, command: &str, body: &InvokeBody) -> Result<(), InvalidArguments> {
+        let (Some(arguments), InvokeBody::Json(Value::Object(values))) =
+            (self.commands.get(command), body)
+        else {
+            return Ok(());
+        };
+
+        for argument in arguments {
+            let mut path = Vec::new();
+            let (key, result) = match argument.find(values) {
+                Some((key, value)) => (key, check(&argument.dt, value, &mut path)),
+                None if is_required(&argument.dt) => (
+                    argument.camel_case.as_str(),
+                    Err("missing required argument".into()),
+                ),
+                None => continue,
+            };
+
+            if let Err(message) = result {
+                path.reverse();
+                return Err(InvalidArguments {
+                    details: Details {
+                        command: command.to_string(),
+                        argument: key.to_string(),
+                        path,
+                        message,
+                    },
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the argument must be provided. Only types which can never be `null` are considered required.
+fn is_required(dt: &DataType) -> bool {
+    matches!(
+        dt,
+        DataType::Primitive(_) | DataType::List(_) | DataType::Map(_)
+    )
+}
+
+/// Check a value against the type. On error `path` contains the location of the invalid value, from the innermost segment outwards.
+fn check(dt: &DataType, value: &Value, path: &mut Vec<PathSegment>) -> Result<(), String> {
+    match dt {
+        DataType::Primitive(primitive) => check_primitive(primitive, value),
+        DataType::Nullable(dt) if !value.is_null() => check(dt, value, path),
+        DataType::List(list) => {
+            let items = value
+                .as_array()
+                .ok_or_else(|| expected("an array", value))?;
+            items.iter().enumerate().try_for_each(|(i, item)| {
+                check(&list.ty, item, path).inspect_err(|_| path.push(PathSegment::Index(i)))
+            })
+        }
+        DataType::Map(map) => {
+            let entries = value
+                .as_object()
+                .ok_or_else(|| expected("an object", value))?;
+            entries.iter().try_for_each(|(key, item)| {
+                check(map.value_ty(), item, path)
+                    .inspect_err(|_| path.push(PathSegment::Key(key.clone())))
+            })
+        }
+        DataType::Tuple(tuple) if !tuple.elements.is_empty() => {
+            let items = value
+                .as_array()
+                .filter(|items| items.len() == tuple.elements.len())
+                .ok_or_else(|| {
+                    expected(
+                        &format!("an array of {} items", tuple.elements.len()),
+                        value,
+                    )
+                })?;
+            tuple
+                .elements
+                .iter()
+                .zip(items)
+                .enumerate()
+                .try_for_each(|(i, (dt, item))| {
+                    check(dt, item, path).inspect_err(|_| path.push(PathSegment::Index(i)))
+                })
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check_primitive(primitive: &Primitive, value: &Value) -> Result<(), String> {
+    let (valid, expectation) = match primitive {
+        Primitive::i8 => (integer_in(value, i8::MIN.into(), i8::MAX.into()), "an i8"),
+        Primitive::i16 => (
+            integer_in(value, i16::MIN.into(), i16::MAX.into()),
+            "an i16",
+        ),
+        Primitive::i32 => (
+            integer_in(value, i32::MIN.into(), i32::MAX.into()),
+            "an i32",
+        ),
+        Primitive::i64 | Primitive::isize => (value.is_i64(), "an i64"),
+        Primitive::u8 => (integer_in(value, 0, u8::MAX.into()), "a u8"),
+        Primitive::u16 => (integer_in(value, 0, u16::MAX.into()), "a u16"),
+        Primitive::u32 => (integer_in(value, 0, u32::MAX.into()), "a u32"),
+        Primitive::u64 | Primitive::usize => (value.is_u64(), "a u64"),
+        Primitive::i128 => (value.is_i64() || value.is_u64(), "an i128"),
+        Primitive::u128 => (value.is_u64(), "a u128"),
+        Primitive::f16 | Primitive::f32 | Primitive::f64 | Primitive::f128 => {
+            (value.is_number(), "a number")
+        }
+        Primitive::bool => (value.is_boolean(), "a boolean"),
+        Primitive::char => (
+            value.as_str().is_some_and(|s| s.chars().count() == 1),
+            "a single character",
+        ),
+        Primitive::str | Primitive::String => (value.is_string(), "a string"),
+        #[allow(unreachable_patterns)]
+        _ => (true, ""),
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(expected(expectation, value))
+    }
+}
+
+fn integer_in(value: &Value, min: i64, max: i64) -> bool {
+    value.as_i64().is_some_and(|v| (min..=max).contains(&v))
+}
+
+fn expected(expectation: &str, value: &Value) -> String {
+    let found = match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(n) => return format!("expected {expectation}, found {n}"),
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    };
+    format!("expected {expectation}, found {found}")
+}
@@ -1,6 +1,21 @@
-use std::{any::TypeId, borrow::Cow, collections::BTreeMap, path::Path};
+use std::{
+    any::TypeId,
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    path::Path,
+    sync::Arc,
+};
 
-use crate::{Casing, Commands, EventRegistry, Events, LanguageExt, event::EventRegistryMeta};
+use crate::{
+    Casing, Commands, EventDefinition, EventRegistry, Events, InvokeContext, LanguageExt, Next,
+    RESPOND_COMMAND, RequestDefinition, Requests, STICKY_COMMAND,
+    arguments::ArgumentValidator,
+    event::EventRegistryMeta,
+    middleware::Middleware,
+    panic::catch_panic,
+    request::RequestRegistry,
+    schema::{SCHEMA_COMMAND, schema},
+};
 use serde::Serialize;
 use specta::{
     Type, Types,
@@ -8,12 +23,18 @@ use specta::{
 };
 #[cfg(any(feature = "javascript", feature = "typescript"))]
 use specta_typescript::semantic;
-use tauri::{Manager, Runtime, ipc::Invoke};
+use tauri::{
+    Manager, Runtime,
+    ipc::{Invoke, InvokeBody},
+};
 
 /// The mode which the error handling is done in the bindings.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub enum ErrorHandlingMode {
     /// Errors will be thrown
+    ///
+    /// Errors returned by commands are wrapped in the generated `CommandError` class,
+    /// which carries the name of the command, the typed error and the original rejection.
     Throw,
     /// Errors will be returned as a status-tagged result object.
     #[default]
@@ -23,6 +44,21 @@ pub enum ErrorHandlingMode {
     /// Successful commands return `{ data: T, error: null }`, while failed
     /// commands return `{ data: null, error: E }`.
     DataError,
+    /// Commands will return an [Effect](https://effect.website) `Effect.Effect<T, E>`.
+    ///
+    /// The command is invoked lazily when the effect is run.
+    /// Commands which return a [`Result`] fail the effect with the rejection of the invoke.
+    /// Commands which can't fail return `Effect.Effect<T, never>`, so any rejection is a defect.
+    ///
+    /// This requires the `effect` package to be installed in your frontend.
+    Effect,
+    /// Commands will return a [neverthrow](https://github.com/supermacro/neverthrow) `ResultAsync<T, E>`.
+    ///
+    /// Commands which return a [`Result`] resolve to an `Err` with the rejection of the invoke.
+    /// Commands which can't fail return `ResultAsync<T, never>`, so any rejection rejects the underlying promise.
+    ///
+    /// This requires the `neverthrow` package to be installed in your frontend.
+    Neverthrow,
 }
 
 /// Builder for configuring Tauri Specta in your application.
@@ -91,6 +127,7 @@ pub enum ErrorHandlingMode {
 #[non_exhaustive]
 pub struct Builder<R: Runtime> {
     commands: Commands<R>,
+    middleware: Middleware<R>,
     cfg: BuilderConfiguration,
 }
 
@@ -105,7 +142,14 @@ pub struct BuilderConfiguration {
     /// Error handling mode used by generated bindings.
     pub error_handling: ErrorHandlingMode,
     /// Event names mapped to their type metadata.
+    ///
+    /// This only holds events registered with [`Event`](crate::Event), not the events of an [`EventGroup`](crate::EventGroup).
+    /// Use [`Self::event_definitions`] for every event and the rest of its metadata.
     pub events: BTreeMap<&'static str, (TypeId, Reference)>,
+    /// Event names mapped to their definition, including the events of any [`EventGroup`](crate::EventGroup).
+    pub event_definitions: BTreeMap<Cow<'static, str>, EventDefinition>,
+    /// Request names mapped to their type metadata.
+    pub requests: BTreeMap<&'static str, RequestDefinition>,
     /// Collected Specta types referenced by commands, events, and manual registrations.
     pub types: Types,
     /// Constants exported alongside generated bindings.
@@ -122,12 +166,27 @@ pub struct BuilderConfiguration {
     pub disable_serde_phases: bool,
     /// Casing applied to generated command and event accessor names.
     pub function_casing: Casing,
+    /// Window labels exported as the `WindowLabel` type for targeted events.
+    pub window_labels: Vec<Cow<'static, str>>,
+    /// Whether command panics are converted into error responses.
+    pub catch_panics: bool,
+    /// Whether the schema is exposed through the [`SCHEMA_COMMAND`](crate::SCHEMA_COMMAND).
+    pub expose_schema: bool,
+    /// Whether invalid command arguments are rejected with a typed `InvalidArguments` error.
+    pub typed_argument_errors: bool,
+    /// Commands which have been disabled using [`Builder::disable_command`].
+    pub disabled_commands: BTreeSet<Cow<'static, str>>,
+    /// Whether the permission required to call each command is included in the generated bindings.
+    pub annotate_permissions: bool,
+    /// Whether [middleware](Builder::middleware) is registered, so commands can be rejected with a [`Rejection`](crate::Rejection).
+    pub has_middleware: bool,
 }
 
 impl<R: Runtime> Default for Builder<R> {
     fn default() -> Self {
         Self {
             commands: Default::default(),
+            middleware: Default::default(),
             cfg: Default::default(),
         }
     }
@@ -137,6 +196,7 @@ impl<R: Runtime> Clone for Builder<R> {
     fn clone(&self) -> Self {
         Self {
             commands: self.commands.clone(),
+            middleware: self.middleware.clone(),
             cfg: self.cfg.clone(),
         }
     }
@@ -175,12 +235,59 @@ impl<R: Runtime> Builder<R> {
     /// ```
     pub fn commands(mut self, commands: Commands<R>) -> Self {
         self.cfg.commands = (commands.1)(&mut self.cfg.types);
+        self.cfg
+            .commands
+            .retain(|command| !self.cfg.disabled_commands.contains(command.name()));
         Self {
             commands,
+            middleware: self.middleware,
             cfg: self.cfg,
         }
     }
 
+    /// Disable a command at runtime, removing it from both the invoke handler and the exported bindings.
+    ///
+    /// The name is the name of the command as it is declared in Rust.
+    /// Invoking a disabled command fails as if the command doesn't exist.
+    ///
+    /// If you know at compile time whether a command should be included, you can use `#[cfg(...)]` in [`collect_commands!`](crate::collect_commands) instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no command with the name has been registered using [`Builder::commands`], so it must be called afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tauri_specta::{Builder, collect_commands};
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn reset_database() {}
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().commands(collect_commands![reset_database]);
+    ///
+    /// if std::env::var("ENABLE_DANGEROUS_COMMANDS").is_err() {
+    ///     builder = builder.disable_command("reset_database");
+    /// }
+    /// ```
+    #[allow(clippy::panic)]
+    pub fn disable_command(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        let name = name.into();
+        if !self.cfg.disabled_commands.contains(&name)
+            && !self
+                .cfg
+                .commands
+                .iter()
+                .any(|command| command.name() == name)
+        {
+            panic!("Can't disable command {name} as it isn't registered!");
+        }
+        self.cfg.commands.retain(|command| command.name() != name);
+        self.cfg.disabled_commands.insert(name);
+        self
+    }
+
     /// Register events with the builder.
     ///
     /// **WARNING:** This method will overwrite any previously registered events.
@@ -197,8 +304,56 @@ impl<R: Runtime> Builder<R> {
     ///
     /// let mut builder = Builder::<tauri::Wry>::new().events(collect_events![DemoEvent]);
     /// ```
+    #[allow(clippy::panic)]
     pub fn events(mut self, events: Events) -> Self {
-        self.cfg.events = events
+        self.cfg.event_definitions = events.definitions(&mut self.cfg.types);
+        self.cfg.events = self
+            .cfg
+            .event_definitions
+            .iter()
+            .filter_map(
+                |(name, event)| match (name, event.group, event.reference()) {
+                    (Cow::Borrowed(name), None, Some(reference)) => {
+                        Some((*name, (event.type_id, reference.clone())))
+                    }
+                    _ => None,
+                },
+            )
+            .collect();
+
+        // The name is looked up by type when emitting, so each type can only have one.
+        let mut names = HashMap::new();
+        for (name, event) in &self.cfg.event_definitions {
+            let name = event.group.unwrap_or(name.as_ref());
+            if let Some(other) = names.insert(event.type_id, name)
+                && other != name
+            {
+                panic!("Event type is registered with multiple names '{other}' and '{name}'!");
+            }
+        }
+
+        self
+    }
+
+    /// Register requests with the builder.
+    ///
+    /// **WARNING:** This method will overwrite any previously registered requests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use serde::{Serialize, Deserialize};
+    /// use specta::Type;
+    /// use tauri_specta::{Builder, collect_requests, Request};
+    ///
+    /// #[derive(Serialize, Deserialize, Type, Request)]
+    /// #[tauri_specta(response = bool)]
+    /// pub struct ConfirmDelete(String);
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().requests(collect_requests![ConfirmDelete]);
+    /// ```
+    pub fn requests(mut self, requests: Requests) -> Self {
+        self.cfg.requests = requests
             .0
             .iter()
             .map(|(k, build)| (*k, build(&mut self.cfg.types)))
@@ -273,9 +428,12 @@ impl<R: Runtime> Builder<R> {
     }
 
     /// Replace the internal implementation of the `typedError` function.
-    /// This would allow integrating with Effect or any other result library.
+    /// This would allow integrating with any other result library.
     /// The implementation must return the shape selected by [`ErrorHandlingMode`].
     ///
+    /// In [`ErrorHandlingMode::Effect`] the function is given a thunk (`() => Promise<T>`) instead of a promise.
+    /// Exporting fails if this is set in [`ErrorHandlingMode::Throw`], which always throws a `CommandError`.
+    ///
     /// ```rust
     /// use tauri_specta::Builder;
     ///
@@ -351,13 +509,296 @@ impl<R: Runtime> Builder<R> {
         self
     }
 
+    /// Register the labels of the windows in your application.
+    ///
+    /// This exports a `WindowLabel` union type to the frontend which is used to type the target of `events.myEvent.emitTo(...)`.
+    /// You can use the [`window_labels!`](crate::window_labels) macro to define a matching Rust enum which can be passed to [`Event::emit_to`](crate::Event::emit_to).
+    ///
+    /// **WARNING:** This method will overwrite any previously registered window labels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tauri_specta::{Builder, window_labels};
+    ///
+    /// window_labels! {
+    ///     pub enum WindowLabel {
+    ///         Main = "main",
+    ///         Settings = "settings",
+    ///     }
+    /// }
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().window_labels(WindowLabel::ALL);
+    /// ```
+    pub fn window_labels<L: Into<Cow<'static, str>>>(
+        mut self,
+        labels: impl IntoIterator<Item = L>,
+    ) -> Self {
+        self.cfg.window_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Register a middleware which runs for every command invoked through [`Builder::invoke_handler`].
+    ///
+    /// Middleware runs in the order it was registered. Call [`Next::run`] to continue to the next middleware or the command,
+    /// or [`InvokeContext::reject`] to respond with a [`Rejection`](crate::Rejection) without running the command.
+    ///
+    /// Synchronous commands have responded by the time [`Next::run`] returns, so timing it measures the command.
+    /// Async commands are spawned onto the async runtime, so timing [`Next::run`] only measures the time taken to spawn them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new()
+    ///     // Gate commands behind a feature flag.
+    ///     .middleware(|ctx, next| {
+    ///         if ctx.command() == "experimental" {
+    ///             return ctx.reject("This command is disabled");
+    ///         }
+    ///
+    ///         next.run(ctx)
+    ///     })
+    ///     // Log every command.
+    ///     .middleware(|ctx, next| {
+    ///         let command = ctx.invoke_name().into_owned();
+    ///         let window = ctx.window_label();
+    ///         let start = std::time::Instant::now();
+    ///         let handled = next.run(ctx);
+    ///         println!("{command} invoked from {window} in {:?}", start.elapsed());
+    ///         handled
+    ///     });
+    /// ```
+    pub fn middleware<F>(mut self, middleware: F) -> Self
+    where
+        F: Fn(InvokeContext<R>, Next<'_, R>) -> bool + Send + Sync + 'static,
+    {
+        self.middleware.push(Arc::new(middleware));
+        self.cfg.has_middleware = true;
+        self
+    }
+
+    /// Convert panics in commands into an error response instead of leaving the frontend without a response.
+    ///
+    /// The generated bindings convert the panic into a `CommandPanic` error.
+    /// In [`ErrorHandlingMode::Result`] commands which return a [`Result`] resolve to `{ status: "panic"; message: string }` instead.
+    /// All other modes treat a panic as an unexpected error, so it's thrown (or is an Effect defect).
+    ///
+    /// Only panics raised while the command is dispatched are caught. The body of an async command runs on the async runtime,
+    /// so a panic inside it can't be caught and the frontend is left without a response.
+    ///
+    /// ```rust
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().catch_panics();
+    /// ```
+    pub fn catch_panics(mut self) -> Self {
+        self.cfg.catch_panics = true;
+        self
+    }
+
+    /// Register a hidden [`SCHEMA_COMMAND`](crate::SCHEMA_COMMAND) command which returns a description of the commands, events, constants and types registered with the builder.
+    ///
+    /// This allows devtools or test drivers to discover the IPC surface of a running application without access to the generated bindings.
+    /// The schema includes a `hash` which changes whenever the IPC surface changes.
+    ///
+    /// The command runs through any registered [middleware](Self::middleware) so it can be restricted like any other command.
+    /// If you're building a plugin you must also allow the command in your plugin's permissions.
+    ///
+    /// ```rust
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().expose_schema();
+    /// ```
+    ///
+    /// The schema can then be fetched from the frontend:
+    ///
+    /// ```js
+    /// const schema = await invoke("__tauri_specta_schema");
+    /// ```
+    pub fn expose_schema(mut self) -> Self {
+        self.cfg.expose_schema = true;
+        self
+    }
+
+    /// Reject commands called with invalid arguments with a typed `InvalidArguments` error, instead of Tauri's error message.
+    ///
+    /// The error is an instance of the generated `InvalidArguments` class with the `command`, the `argument`, a `message`
+    /// and the `path` of the invalid value within the argument (e.g. `["items", 2]`).
+    /// The generated bindings include it in the error type of every command which returns a [`Result`], so it can be handled like any other error.
+    /// This is useful when the frontend and backend can be out of sync, like during a rolling upgrade.
+    ///
+    /// Arguments are checked against their types by the invoke handler before the command deserializes them.
+    /// Values which can only be validated by deserializing them (e.g. structs or enums) are still rejected by Tauri with its own error message.
+    ///
+    /// ```rust
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().typed_argument_errors();
+    /// ```
+    pub fn typed_argument_errors(mut self) -> Self {
+        self.cfg.typed_argument_errors = true;
+        self
+    }
+
+    /// Include the [permission](https://v2.tauri.app/security/permissions/) required to call each command in the generated bindings.
+    ///
+    /// The documentation of each command mentions the permission (e.g. `my-plugin:allow-add-numbers`)
+    /// and a `permissions` constant is exported which maps each command to its permission.
+    ///
+    /// The identifiers match the permissions generated by `tauri_plugin::Builder` or [`Permissions`](crate::Permissions).
+    /// Only plugin commands are gated by permission identifiers, so exporting fails if [`Builder::plugin_name`] isn't set.
+    ///
+    /// ```rust
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new()
+    ///     .plugin_name("my-plugin")
+    ///     .annotate_permissions();
+    /// ```
+    pub fn annotate_permissions(mut self) -> Self {
+        self.cfg.annotate_permissions = true;
+        self
+    }
+
     /// The Tauri invoke handler to trigger commands registered with the builder.
     pub fn invoke_handler(&self) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static {
-        let commands = self.commands.0.clone();
-        move |invoke| commands(invoke)
+        let mut commands = self.commands.0.clone();
+        if self.cfg.typed_argument_errors {
+            let validator = ArgumentValidator::new(&self.cfg);
+            let inner = commands;
+            commands = Arc::new(move |invoke: Invoke<R>| {
+                match validator.validate(invoke.message.command(), invoke.message.payload()) {
+                    Ok(()) => inner(invoke),
+                    Err(err) => {
+                        invoke.resolver.reject(err);
+                        true
+                    }
+                }
+            });
+        }
+        if !self.cfg.disabled_commands.is_empty() {
+            let disabled = self.cfg.disabled_commands.clone();
+            let inner = commands;
+            commands = Arc::new(move |invoke: Invoke<R>| {
+                !disabled.contains(invoke.message.command()) && inner(invoke)
+            });
+        }
+        if self.cfg.expose_schema {
+            let schema = schema(&self.cfg);
+            let inner = commands;
+            commands = Arc::new(move |invoke: Invoke<R>| {
+                if invoke.message.command() == SCHEMA_COMMAND {
+                    invoke.resolver.resolve(schema.clone());
+                    true
+                } else {
+                    inner(invoke)
+                }
+            });
+        }
+        if self
+            .cfg
+            .event_definitions
+            .values()
+            .any(|event| event.sticky)
+        {
+            let inner = commands;
+            commands = Arc::new(move |invoke: Invoke<R>| {
+                if invoke.message.command() != STICKY_COMMAND {
+                    return inner(invoke);
+                }
+
+                let InvokeBody::Json(args) = invoke.message.payload() else {
+                    invoke.resolver.reject("Expected a JSON payload");
+                    return true;
+                };
+                let Some(event) = args.get("event").and_then(serde_json::Value::as_str) else {
+                    invoke.resolver.reject("Missing `event` argument");
+                    return true;
+                };
+                let webview = invoke.message.webview();
+                let payload = webview.try_state::<EventRegistry>().and_then(|registry| {
+                    registry.current(event, webview.label(), webview.window().label())
+                });
+                // The payload is wrapped so a `null` payload can be told apart from no payload.
+                invoke
+                    .resolver
+                    .resolve(payload.map(|payload| serde_json::json!({ "payload": payload })));
+                true
+            });
+        }
+        if !self.cfg.requests.is_empty() {
+            let inner = commands;
+            commands = Arc::new(move |invoke: Invoke<R>| {
+                if invoke.message.command() != RESPOND_COMMAND {
+                    return inner(invoke);
+                }
+
+                let InvokeBody::Json(args) = invoke.message.payload() else {
+                    invoke.resolver.reject("Expected a JSON payload");
+                    return true;
+                };
+                let Some(id) = args.get("id").and_then(serde_json::Value::as_str) else {
+                    invoke.resolver.reject("Missing `id` argument");
+                    return true;
+                };
+                let response = match args.get("error").and_then(serde_json::Value::as_str) {
+                    Some(error) => Err(error.to_string()),
+                    None => Ok(args.get("response").cloned().unwrap_or_default()),
+                };
+                // The webview is checked so only the webviews the request was sent to can answer it.
+                let webview = invoke.message.webview();
+                let answered = webview
+                    .try_state::<RequestRegistry>()
+                    .is_some_and(|registry| {
+                        registry.respond(id, webview.label(), webview.window().label(), response)
+                    });
+                if answered {
+                    invoke.resolver.resolve(());
+                } else {
+                    invoke
+                        .resolver
+                        .reject("No pending request with this ID was sent to the webview");
+                }
+                true
+            });
+        }
+        let middleware = self.middleware.clone();
+        let plugin_name = self.cfg.plugin_name;
+        let catch_panics = self.cfg.catch_panics;
+        move |invoke| {
+            #[cfg(feature = "tracing")]
+            let span = tracing::info_span!(
+                "tauri_specta::invoke",
+                command = invoke.message.command(),
+                plugin = plugin_name,
+                webview = invoke.message.webview_ref().label(),
+                outcome = tracing::field::Empty,
+            );
+            #[cfg(feature = "tracing")]
+            let _enter = span.enter();
+
+            let dispatch = |invoke| {
+                let handled = if middleware.0.is_empty() {
+                    commands(invoke)
+                } else {
+                    Next::new(&middleware.0, &*commands)
+                        .run(InvokeContext::new(invoke, plugin_name))
+                };
+                #[cfg(feature = "tracing")]
+                span.record("outcome", if handled { "handled" } else { "unhandled" });
+                handled
+            };
+            if catch_panics {
+                catch_panic(invoke, dispatch)
+            } else {
+                dispatch(invoke)
+            }
+        }
     }
 
-    /// Mount all of the events in the builder onto a Tauri app.
+    /// Mount all of the events and requests in the builder onto a Tauri app.
     ///
     /// This should be called within [`tauri::Builder::setup`](tauri::Builder::setup) like the example below.
     ///
@@ -379,13 +820,27 @@ impl<R: Runtime> Builder<R> {
     /// ```
     pub fn mount_events(&self, handle: &impl Manager<R>) {
         let registry = EventRegistry::get_or_manage(handle);
-        let mut map = registry.0.write().expect("Failed to lock EventRegistry");
+        let mut map = registry
+            .events
+            .write()
+            .expect("Failed to lock EventRegistry");
 
-        for (tid, _) in self.cfg.events.values() {
+        for (name, event) in &self.cfg.event_definitions {
+            map.insert(
+                event.type_id,
+                EventRegistryMeta {
+                    plugin_name: self.cfg.plugin_name,
+                    // Events in a group are registered under the group's name.
+                    name: event.group.map_or_else(|| name.clone(), Cow::Borrowed),
+                },
+            );
+        }
+        for (name, request) in &self.cfg.requests {
             map.insert(
-                *tid,
+                request.type_id,
                 EventRegistryMeta {
                     plugin_name: self.cfg.plugin_name,
+                    name: Cow::Borrowed(*name),
                 },
             );
         }
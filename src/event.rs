@@ -2,21 +2,30 @@ use std::{
     any::TypeId,
     borrow::Cow,
     collections::{BTreeMap, HashMap},
-    sync::RwLock,
+    sync::{Arc, Mutex, RwLock},
+    time::Duration,
 };
 
 use serde::{Serialize, de::DeserializeOwned};
-use specta::{Type, Types, datatype::Reference};
+use specta::{
+    Type, Types,
+    datatype::{DataType, DeprecatedType, Reference},
+};
 use tauri::{Emitter, EventId, EventTarget, Listener, Manager, Runtime};
 
 use crate::name::resolve_tauri_event_name;
 
+/// The name of the hidden command which returns the last payload of a [sticky](Event::STICKY) event.
+pub const STICKY_COMMAND: &str = "__tauri_specta_sticky";
+
 /// A wrapper around the output of the `collect_commands` macro.
 ///
 /// This acts to seal the implementation details of the macro.
 #[derive(Clone, Default)]
 #[allow(clippy::type_complexity)]
-pub struct Events(pub(crate) BTreeMap<&'static str, fn(&mut Types) -> (TypeId, Reference)>);
+pub struct Events(
+    pub(crate) BTreeMap<Cow<'static, str>, Arc<dyn Fn(&mut Types) -> EventDefinition + Send + Sync>>,
+);
 
 impl Events {
     /// Combines two event collections.
@@ -28,31 +37,273 @@ impl Events {
         self.0.extend(other.0);
         self
     }
+
+    /// The definitions of the collected events by the name they're registered with.
+    ///
+    /// The events of an [`EventGroup`] are registered as `group:event` and share the [`TypeId`] of the group.
+    /// Their types are added to `types`.
+    pub fn definitions(&self, types: &mut Types) -> BTreeMap<Cow<'static, str>, EventDefinition> {
+        self.0
+            .iter()
+            .map(|(name, build)| (name.clone(), build(types)))
+            .collect()
+    }
+}
+
+/// The metadata of an event registered with the [`Builder`](crate::Builder).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct EventDefinition {
+    /// The [`TypeId`] of the event's type, or of the [`EventGroup`] it belongs to.
+    pub type_id: TypeId,
+    /// The event's payload type.
+    pub data_type: DataType,
+    /// The direction the event can be sent in.
+    pub direction: EventDirection,
+    /// The name of the [`EventGroup`] the event belongs to, if any.
+    pub group: Option<&'static str>,
+    /// The documentation of the event.
+    pub docs: Cow<'static, str>,
+    /// Whether the event is deprecated.
+    pub deprecated: Option<DeprecatedType>,
+    /// Whether the last payload of the event is remembered.
+    pub sticky: bool,
+}
+
+impl EventDefinition {
+    /// The reference to the event's type, which is held by [`BuilderConfiguration::events`](crate::BuilderConfiguration::events)
+    /// along with the [`TypeId`](Self::type_id).
+    ///
+    /// This is `None` for the events of an [`EventGroup`] whose payload is not a named type.
+    pub fn reference(&self) -> Option<&Reference> {
+        match &self.data_type {
+            DataType::Reference(reference) => Some(reference),
+            _ => None,
+        }
+    }
+}
+
+/// Convert a deprecation message to the [`DeprecatedType`] specta uses for `#[deprecated]`.
+pub(crate) fn deprecated_type(note: Option<&'static str>) -> Option<DeprecatedType> {
+    note.map(|note| match note {
+        "" => DeprecatedType::Deprecated,
+        note => DeprecatedType::DeprecatedWithSince {
+            since: None,
+            note: note.into(),
+        },
+    })
+}
+
+/// The direction an [`Event`] can be sent in.
+///
+/// You can set this using `#[tauri_specta(direction = "to_frontend" | "to_backend" | "both")]` with the [`Event`](macro@crate::Event) derive macro.
+/// The generated bindings only include the methods allowed by the direction, and calling a disallowed method in Rust fails to compile.
+///
+/// ```rust,compile_fail
+/// use tauri_specta::{Event, EventDirection};
+///
+/// #[derive(Clone, serde::Serialize, specta::Type)]
+/// struct ButtonClicked;
+///
+/// impl Event for ButtonClicked {
+///     const NAME: &'static str = "button-clicked";
+///     const DIRECTION: EventDirection = EventDirection::ToBackend;
+/// }
+///
+/// fn click(app: &tauri::AppHandle) {
+///     ButtonClicked.emit(app).ok();
+/// }
+///
+/// let _ = click;
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum EventDirection {
+    /// The event is emitted by the backend and listened to by the frontend.
+    ToFrontend,
+    /// The event is emitted by the frontend and listened to by the backend.
+    ToBackend,
+    /// The event can be emitted and listened to by both the frontend and the backend.
+    #[default]
+    Both,
+}
+
+/// Fails the build when an event which can't be emitted from the backend is emitted.
+#[allow(clippy::panic)]
+const fn assert_backend_emits(direction: EventDirection) {
+    if matches!(direction, EventDirection::ToBackend) {
+        panic!("This event can't be emitted from the backend as its direction is `ToBackend`");
+    }
+}
+
+/// Fails the build when an event which can't be listened to in the backend is listened to.
+#[allow(clippy::panic)]
+const fn assert_backend_listens(direction: EventDirection) {
+    if matches!(direction, EventDirection::ToFrontend) {
+        panic!("This event can't be listened to in the backend as its direction is `ToFrontend`");
+    }
 }
 
-#[derive(Default)]
 pub(crate) struct EventRegistryMeta {
     pub plugin_name: Option<&'static str>,
+    /// The name the event (or event group) was registered with, which may differ from [`Event::NAME`] if it was renamed in `collect_events`.
+    pub name: Cow<'static, str>,
 }
 
 /// A struct for managing events that is put into Tauri's state.
 #[derive(Default)]
-pub(crate) struct EventRegistry(pub(crate) RwLock<HashMap<TypeId, EventRegistryMeta>>);
+pub(crate) struct EventRegistry {
+    pub(crate) events: RwLock<HashMap<TypeId, EventRegistryMeta>>,
+    /// The last payloads of sticky events by event name, along with the target they were emitted to.
+    sticky: Mutex<HashMap<String, Vec<(EventTarget, serde_json::Value)>>>,
+    /// The latest payload of throttled events waiting to be emitted once the interval has passed, by event name and target.
+    /// An entry only exists while a payload was emitted within the interval.
+    throttled: Mutex<HashMap<(String, EventTarget), Option<serde_json::Value>>>,
+}
 
 impl EventRegistry {
     /// gets the name of the event (taking into account plugin prefixes) and ensuring it was correctly mounted to the current app.
-    #[allow(clippy::panic)]
     pub fn get_event_name<E: Event, R: Runtime>(handle: &impl Manager<R>) -> Cow<'static, str> {
+        let (plugin_name, name) = Self::get_registered::<E, R>(handle, E::NAME);
+        match plugin_name {
+            Some(_) => resolve_tauri_event_name(plugin_name, &name)
+                .into_owned()
+                .into(),
+            None => name,
+        }
+    }
+
+    /// gets the name of an event in the [`EventGroup`] (taking into account plugin prefixes) and ensuring it was correctly mounted to the current app.
+    pub fn get_group_event_name<G: EventGroup, R: Runtime>(
+        handle: &impl Manager<R>,
+        event: &str,
+    ) -> Cow<'static, str> {
+        let (plugin_name, name) = Self::get_registered::<G, R>(handle, G::NAME);
+        resolve_tauri_event_name(plugin_name, &format!("{name}:{event}"))
+            .into_owned()
+            .into()
+    }
+
+    #[allow(clippy::panic)]
+    pub(crate) fn get_registered<T: 'static, R: Runtime>(
+        handle: &impl Manager<R>,
+        type_name: &str,
+    ) -> (Option<&'static str>, Cow<'static, str>) {
         let this = handle.try_state::<EventRegistry>().expect(
             "EventRegistry not found in Tauri state - Did you forget to call Builder::mount_events?",
         ).inner();
 
-        let map = this.0.read().expect("Failed to read EventRegistry");
+        let map = this.events.read().expect("Failed to read EventRegistry");
         let meta = map
-            .get(&TypeId::of::<E>())
-            .unwrap_or_else(|| panic!("Event {} not found in registry!", E::NAME));
+            .get(&TypeId::of::<T>())
+            .unwrap_or_else(|| panic!("Event {type_name} not found in registry!"));
 
-        resolve_tauri_event_name(meta.plugin_name, E::NAME)
+        (meta.plugin_name, meta.name.clone())
+    }
+
+    /// Remember the payload of a sticky event emitted to the target.
+    pub fn remember<R: Runtime>(
+        handle: &impl Manager<R>,
+        name: &str,
+        target: EventTarget,
+        payload: &impl Serialize,
+    ) -> tauri::Result<()> {
+        let payload = serde_json::to_value(payload)?;
+        let registry = Self::get_or_manage(handle);
+        let mut sticky = registry
+            .sticky
+            .lock()
+            .expect("Failed to lock EventRegistry");
+        let payloads = sticky.entry(name.to_string()).or_default();
+        // A payload emitted to every target replaces the ones emitted to specific targets.
+        if target == EventTarget::Any {
+            payloads.clear();
+        } else {
+            payloads.retain(|(existing, _)| *existing != target);
+        }
+        payloads.push((target, payload));
+        Ok(())
+    }
+
+    /// Emit the payload to the target, unless a payload was emitted to it within the interval.
+    /// In that case only the latest payload is emitted once the interval has passed.
+    pub fn throttle<R: Runtime>(
+        handle: &(impl Emitter<R> + Manager<R>),
+        name: String,
+        target: EventTarget,
+        payload: &impl Serialize,
+        interval: Duration,
+        sticky: bool,
+    ) -> tauri::Result<()> {
+        let payload = serde_json::to_value(payload)?;
+        let key = (name, target);
+        {
+            let registry = Self::get_or_manage(handle);
+            let mut throttled = registry
+                .throttled
+                .lock()
+                .expect("Failed to lock EventRegistry");
+            if let Some(pending) = throttled.get_mut(&key) {
+                // The scheduled emit will pick up the latest payload.
+                *pending = Some(payload);
+                return Ok(());
+            }
+            throttled.insert(key.clone(), None);
+        }
+
+        let (name, target) = key.clone();
+        let app = handle.app_handle().clone();
+        // Emit the latest payload at the end of each interval, until no payload was emitted during one.
+        tauri::async_runtime::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                let payload = {
+                    let registry = app.state::<Self>();
+                    let mut throttled = registry
+                        .throttled
+                        .lock()
+                        .expect("Failed to lock EventRegistry");
+                    match throttled.get_mut(&key).and_then(Option::take) {
+                        Some(payload) => payload,
+                        None => {
+                            throttled.remove(&key);
+                            return;
+                        }
+                    }
+                };
+                let (name, target) = &key;
+                if let Err(_err) = Self::deliver(&app, name, target.clone(), payload, sticky) {
+                    #[cfg(feature = "tracing")]
+                    tracing::error!(event = %name, error = %_err, "tauri_specta: failed to emit throttled event");
+                }
+            }
+        });
+
+        Self::deliver(handle, &name, target, payload, sticky)
+    }
+
+    fn deliver<R: Runtime>(
+        handle: &(impl Emitter<R> + Manager<R>),
+        name: &str,
+        target: EventTarget,
+        payload: serde_json::Value,
+        sticky: bool,
+    ) -> tauri::Result<()> {
+        handle.emit_to(target.clone(), name, &payload)?;
+        if sticky {
+            Self::remember(handle, name, target, &payload)?;
+        }
+        Ok(())
+    }
+
+    /// The last payload of the sticky event emitted to the webview.
+    pub fn current(&self, name: &str, webview: &str, window: &str) -> Option<serde_json::Value> {
+        let sticky = self.sticky.lock().expect("Failed to lock EventRegistry");
+        sticky
+            .get(name)?
+            .iter()
+            .rev()
+            .find(|(target, _)| target_matches(target, webview, window))
+            .map(|(_, payload)| payload.clone())
     }
 
     pub fn get_or_manage<R: Runtime>(handle: &impl Manager<R>) -> tauri::State<'_, Self> {
@@ -64,8 +315,19 @@ impl EventRegistry {
     }
 }
 
+/// Whether something emitted to the target is received by the webview.
+pub(crate) fn target_matches(target: &EventTarget, webview: &str, window: &str) -> bool {
+    match target {
+        EventTarget::Any => true,
+        EventTarget::AnyLabel { label } => label == webview || label == window,
+        EventTarget::Webview { label } | EventTarget::WebviewWindow { label } => label == webview,
+        EventTarget::Window { label } => label == window,
+        _ => false,
+    }
+}
+
 /// A typed event that was emitted.
-pub struct TypedEvent<T: Event> {
+pub struct TypedEvent<T> {
     /// The [`EventId`] of the handler that was triggered.
     pub id: EventId,
     /// The event payload.
@@ -73,15 +335,21 @@ pub struct TypedEvent<T: Event> {
 }
 
 macro_rules! make_handler {
-    ($handler:ident) => {
-        move |event| {
+    ($handler:ident, $name:ident) => {{
+        #[cfg(feature = "tracing")]
+        let name = $name.clone();
+
+        move |event: tauri::Event| {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(event = %name, id = event.id(), "tauri_specta::listen");
+
             $handler(TypedEvent {
                 id: event.id(),
                 payload: serde_json::from_str(event.payload())
                     .expect("Failed to deserialize event payload"),
             });
         }
-    };
+    }};
 }
 
 /// Extends your event type with typesafe methods for listening to and emitting events.
@@ -116,18 +384,53 @@ macro_rules! make_handler {
 /// ```
 pub trait Event: Type + 'static {
     /// The unique name for this event. Derived from the struct's name via the [`Event`](macro@crate::Event) derive macro.
+    ///
+    /// This can be overridden when registering the event with `collect_events![MyEvent as "my-name"]`,
+    /// which is required to register multiple instantiations of a generic event.
     const NAME: &'static str;
 
+    /// The direction the event can be sent in. Defaults to [`EventDirection::Both`].
+    ///
+    /// Calling an emit or listen method the direction doesn't allow fails to compile.
+    const DIRECTION: EventDirection = EventDirection::Both;
+
+    /// The documentation of the event, which is rendered on the event in the generated bindings.
+    /// This is only used if the type has no doc comments, which are rendered instead.
+    ///
+    /// The [`Event`](macro@crate::Event) derive macro sets this from the type's doc comments.
+    const DOCS: &'static str = "";
+
+    /// The deprecation message of the event, if it's deprecated. This is `Some("")` if it's deprecated without a message.
+    /// This is only used if the type has no `#[deprecated]` attribute, which is rendered instead.
+    ///
+    /// The [`Event`](macro@crate::Event) derive macro sets this from the type's `#[deprecated]` attribute.
+    const DEPRECATED: Option<&'static str> = None;
+
+    /// Whether the last payload emitted by the backend is remembered, so windows opened after it was emitted can still read it.
+    /// This is useful for events which represent state, like a connection status.
+    ///
+    /// The generated bindings of sticky events have a `current()` method which returns the remembered payload
+    /// and a `listenWithCurrent(cb)` method which calls the callback with it before listening for new payloads.
+    /// Payloads are remembered per target, except ones emitted with [`Self::emit_filter`] which are not remembered.
+    ///
+    /// You can set this using `#[tauri_specta(sticky)]` with the [`Event`](macro@crate::Event) derive macro.
+    const STICKY: bool = false;
+
+    /// The interval [`Self::emit`] and [`Self::emit_to`] throttle the event to, if any. Refer to [`Self::emit_throttled`].
+    ///
+    /// You can set this using `#[tauri_specta(throttle_ms = 50)]` with the [`Event`](macro@crate::Event) derive macro.
+    const THROTTLE: Option<Duration> = None;
+
     /// Listen to an emitted event on this manager.
     fn listen<F, R: Runtime, H: Listener<R> + Manager<R>>(handle: &H, handler: F) -> EventId
     where
         F: Fn(TypedEvent<Self>) + Send + 'static,
         Self: DeserializeOwned,
     {
-        handle.listen(
-            EventRegistry::get_event_name::<Self, _>(handle),
-            make_handler!(handler),
-        )
+        const { assert_backend_listens(Self::DIRECTION) };
+        let name = EventRegistry::get_event_name::<Self, _>(handle);
+        let handler = make_handler!(handler, name);
+        handle.listen(name, handler)
     }
 
     /// Listen to an emitted event to any [target](EventTarget).
@@ -136,10 +439,10 @@ pub trait Event: Type + 'static {
         F: Fn(TypedEvent<Self>) + Send + 'static,
         Self: DeserializeOwned,
     {
-        handle.listen_any(
-            EventRegistry::get_event_name::<Self, _>(handle),
-            make_handler!(handler),
-        )
+        const { assert_backend_listens(Self::DIRECTION) };
+        let name = EventRegistry::get_event_name::<Self, _>(handle);
+        let handler = make_handler!(handler, name);
+        handle.listen_any(name, handler)
     }
 
     /// Listen to an event on this manager only once.
@@ -148,10 +451,10 @@ pub trait Event: Type + 'static {
         F: FnOnce(TypedEvent<Self>) + Send + 'static,
         Self: DeserializeOwned,
     {
-        handle.once(
-            EventRegistry::get_event_name::<Self, _>(handle),
-            make_handler!(handler),
-        )
+        const { assert_backend_listens(Self::DIRECTION) };
+        let name = EventRegistry::get_event_name::<Self, _>(handle);
+        let handler = make_handler!(handler, name);
+        handle.once(name, handler)
     }
 
     /// Listens once to an emitted event to any [target](EventTarget) .
@@ -162,10 +465,10 @@ pub trait Event: Type + 'static {
         F: FnOnce(TypedEvent<Self>) + Send + 'static,
         Self: DeserializeOwned,
     {
-        handle.once_any(
-            EventRegistry::get_event_name::<Self, _>(handle),
-            make_handler!(handler),
-        )
+        const { assert_backend_listens(Self::DIRECTION) };
+        let name = EventRegistry::get_event_name::<Self, _>(handle);
+        let handler = make_handler!(handler, name);
+        handle.once_any(name, handler)
     }
 
     /// Emits an event to all [targets](EventTarget).
@@ -173,7 +476,18 @@ pub trait Event: Type + 'static {
     where
         Self: Serialize + Clone,
     {
-        handle.emit(&EventRegistry::get_event_name::<Self, _>(handle), self)
+        const { assert_backend_emits(Self::DIRECTION) };
+        if let Some(interval) = Self::THROTTLE {
+            return self.emit_throttled(handle, interval);
+        }
+        let name = EventRegistry::get_event_name::<Self, _>(handle);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(event = %name, "tauri_specta::emit");
+        handle.emit(&name, self)?;
+        if Self::STICKY {
+            EventRegistry::remember(handle, &name, EventTarget::Any, self)?;
+        }
+        Ok(())
     }
 
     /// Emits an event to all [targets](EventTarget) matching the given target.
@@ -185,14 +499,68 @@ pub trait Event: Type + 'static {
     where
         Self: Serialize + Clone,
     {
-        handle.emit_to(
+        const { assert_backend_emits(Self::DIRECTION) };
+        if let Some(interval) = Self::THROTTLE {
+            return self.emit_to_throttled(handle, target, interval);
+        }
+        let name = EventRegistry::get_event_name::<Self, _>(handle);
+        let target = target.into();
+        #[cfg(feature = "tracing")]
+        tracing::debug!(event = %name, target = ?target, "tauri_specta::emit_to");
+        handle.emit_to(target.clone(), &name, self)?;
+        if Self::STICKY {
+            EventRegistry::remember(handle, &name, target, self)?;
+        }
+        Ok(())
+    }
+
+    /// Emits an event to all [targets](EventTarget), at most once per interval.
+    ///
+    /// If the event was emitted within the interval, the payload is emitted once the interval has passed instead.
+    /// Payloads emitted in the meantime replace it, so the latest payload of a burst is always delivered.
+    /// This is useful for progress events emitted from tight loops.
+    fn emit_throttled<R: Runtime, H: Emitter<R> + Manager<R>>(
+        &self,
+        handle: &H,
+        interval: Duration,
+    ) -> tauri::Result<()>
+    where
+        Self: Serialize + Clone,
+    {
+        const { assert_backend_emits(Self::DIRECTION) };
+        self.emit_to_throttled(handle, EventTarget::Any, interval)
+    }
+
+    /// Emits an event to all [targets](EventTarget) matching the given target, at most once per interval.
+    ///
+    /// Each target is throttled separately. Refer to [`Self::emit_throttled`].
+    fn emit_to_throttled<R: Runtime, H: Emitter<R> + Manager<R>, I: Into<EventTarget>>(
+        &self,
+        handle: &H,
+        target: I,
+        interval: Duration,
+    ) -> tauri::Result<()>
+    where
+        Self: Serialize + Clone,
+    {
+        const { assert_backend_emits(Self::DIRECTION) };
+        let name = EventRegistry::get_event_name::<Self, _>(handle);
+        let target = target.into();
+        #[cfg(feature = "tracing")]
+        tracing::debug!(event = %name, target = ?target, interval = ?interval, "tauri_specta::emit_throttled");
+        EventRegistry::throttle(
+            handle,
+            name.into_owned(),
             target,
-            &EventRegistry::get_event_name::<Self, _>(handle),
             self,
+            interval,
+            Self::STICKY,
         )
     }
 
     /// Emits an event to all [targets](EventTarget) based on the given filter.
+    ///
+    /// The event is not [throttled](Self::THROTTLE) or [remembered](Self::STICKY).
     fn emit_filter<F, R: Runtime, H: Emitter<R> + Manager<R>>(
         &self,
         handle: &H,
@@ -202,10 +570,216 @@ pub trait Event: Type + 'static {
         F: Fn(&EventTarget) -> bool,
         Self: Serialize + Clone,
     {
-        handle.emit_filter(
-            &EventRegistry::get_event_name::<Self, _>(handle),
-            self,
-            filter,
-        )
+        const { assert_backend_emits(Self::DIRECTION) };
+        let name = EventRegistry::get_event_name::<Self, _>(handle);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(event = %name, "tauri_specta::emit_filter");
+        handle.emit_filter(&name, self, filter)
+    }
+}
+
+/// Extends an enum with typesafe methods for emitting and listening to each of its variants as a separate event.
+///
+/// Each variant becomes an event named `<group>:<variant>`, whose payload is the variant's field (or `null` for unit variants).
+/// In the frontend they are exported as a nested object, e.g. `events.download.started`.
+///
+/// You should rely on the [`EventGroup`](macro@crate::EventGroup) derive macro to implement this for you.
+/// Variants must have a single unnamed field or no fields at all, and the field's type must implement [`Type`].
+/// The doc comments and `#[deprecated]` attributes of the variants are rendered on their events in the generated bindings.
+/// Event groups can't be [sticky](Event::STICKY), so `#[tauri_specta(sticky)]` fails to compile.
+///
+/// # Example
+/// ```rust
+/// use serde::{Serialize, Deserialize};
+/// use specta::Type;
+/// use tauri_specta::EventGroup;
+/// use tauri::AppHandle;
+///
+/// #[derive(Debug, Clone, Serialize, Deserialize, Type)]
+/// pub struct DownloadFailed {
+///     reason: String,
+/// }
+///
+/// #[derive(Debug, EventGroup)]
+/// pub enum Download {
+///     Started(u32),
+///     Failed(DownloadFailed),
+///     Finished,
+/// }
+///
+/// fn use_events(app_handle: AppHandle<tauri::Wry>) {
+///     Download::listen(&app_handle, |event| match event.payload {
+///         Download::Started(id) => println!("Started {id}"),
+///         Download::Failed(failed) => println!("Failed {failed:?}"),
+///         Download::Finished => println!("Finished"),
+///     });
+///
+///     Download::Started(1).emit(&app_handle).ok();
+/// }
+/// ```
+pub trait EventGroup: Sized + 'static {
+    /// The name of the group, which prefixes the names of its events. Derived from the enum's name via the [`EventGroup`](macro@crate::EventGroup) derive macro.
+    const NAME: &'static str;
+
+    /// The names of the events in the group, without the group's prefix.
+    const EVENTS: &'static [&'static str];
+
+    /// The direction the events in the group can be sent in. Defaults to [`EventDirection::Both`].
+    const DIRECTION: EventDirection = EventDirection::Both;
+
+    /// The name of this value's event, without the group's prefix.
+    fn event(&self) -> &'static str;
+
+    #[doc(hidden)]
+    fn __payload(&self) -> serde_json::Result<serde_json::Value> {
+        Err(serde::ser::Error::custom(format!(
+            "Events in the group {} can't be emitted from the backend",
+            Self::NAME
+        )))
+    }
+
+    #[doc(hidden)]
+    fn __from_payload(event: &str, _payload: &str) -> serde_json::Result<Self> {
+        Err(serde::de::Error::custom(format!(
+            "Event {event} in the group {} can't be listened to in the backend",
+            Self::NAME
+        )))
+    }
+
+    /// Listen to all events in the group emitted on this manager.
+    ///
+    /// Returns the [`EventId`] of the handler registered for each event.
+    fn listen<F, R: Runtime, H: Listener<R> + Manager<R>>(handle: &H, handler: F) -> Vec<EventId>
+    where
+        F: Fn(TypedEvent<Self>) + Send + Sync + 'static,
+    {
+        const { assert_backend_listens(Self::DIRECTION) };
+        let handler = Arc::new(handler);
+        Self::EVENTS
+            .iter()
+            .map(|event| {
+                let name = EventRegistry::get_group_event_name::<Self, _>(handle, event);
+                handle.listen(
+                    name.clone(),
+                    make_group_handler(handler.clone(), event, name),
+                )
+            })
+            .collect()
+    }
+
+    /// Listen to all events in the group emitted to any [target](EventTarget).
+    ///
+    /// Returns the [`EventId`] of the handler registered for each event.
+    fn listen_any<F, R: Runtime, H: Listener<R> + Manager<R>>(
+        handle: &H,
+        handler: F,
+    ) -> Vec<EventId>
+    where
+        F: Fn(TypedEvent<Self>) + Send + Sync + 'static,
+    {
+        const { assert_backend_listens(Self::DIRECTION) };
+        let handler = Arc::new(handler);
+        Self::EVENTS
+            .iter()
+            .map(|event| {
+                let name = EventRegistry::get_group_event_name::<Self, _>(handle, event);
+                handle.listen_any(
+                    name.clone(),
+                    make_group_handler(handler.clone(), event, name),
+                )
+            })
+            .collect()
+    }
+
+    /// Emits this value's event to all [targets](EventTarget).
+    fn emit<R: Runtime, H: Emitter<R> + Manager<R>>(&self, handle: &H) -> tauri::Result<()> {
+        const { assert_backend_emits(Self::DIRECTION) };
+        let name = EventRegistry::get_group_event_name::<Self, _>(handle, self.event());
+        #[cfg(feature = "tracing")]
+        tracing::debug!(event = %name, "tauri_specta::emit");
+        handle.emit(&name, self.__payload()?)
+    }
+
+    /// Emits this value's event to all [targets](EventTarget) matching the given target.
+    fn emit_to<R: Runtime, H: Emitter<R> + Manager<R>, I: Into<EventTarget>>(
+        &self,
+        handle: &H,
+        target: I,
+    ) -> tauri::Result<()> {
+        const { assert_backend_emits(Self::DIRECTION) };
+        let name = EventRegistry::get_group_event_name::<Self, _>(handle, self.event());
+        let target = target.into();
+        #[cfg(feature = "tracing")]
+        tracing::debug!(event = %name, target = ?target, "tauri_specta::emit_to");
+        handle.emit_to(target, &name, self.__payload()?)
+    }
+
+    /// Emits this value's event to all [targets](EventTarget) based on the given filter.
+    fn emit_filter<F, R: Runtime, H: Emitter<R> + Manager<R>>(
+        &self,
+        handle: &H,
+        filter: F,
+    ) -> tauri::Result<()>
+    where
+        F: Fn(&EventTarget) -> bool,
+    {
+        const { assert_backend_emits(Self::DIRECTION) };
+        let name = EventRegistry::get_group_event_name::<Self, _>(handle, self.event());
+        #[cfg(feature = "tracing")]
+        tracing::debug!(event = %name, "tauri_specta::emit_filter");
+        handle.emit_filter(&name, self.__payload()?, filter)
+    }
+}
+
+fn make_group_handler<G: EventGroup, F>(
+    handler: Arc<F>,
+    event: &'static str,
+    #[allow(unused_variables)] name: Cow<'static, str>,
+) -> impl Fn(tauri::Event) + Send + 'static
+where
+    F: Fn(TypedEvent<G>) + Send + Sync + 'static,
+{
+    move |e: tauri::Event| {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(event = %name, id = e.id(), "tauri_specta::listen");
+
+        handler(TypedEvent {
+            id: e.id(),
+            payload: G::__from_payload(event, e.payload())
+                .expect("Failed to deserialize event payload"),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throttled_payloads_are_forgotten_once_the_interval_passes() {
+        let app = tauri::test::mock_app();
+        for i in 0..10 {
+            EventRegistry::throttle(
+                &app,
+                "progress".into(),
+                EventTarget::Any,
+                &i,
+                Duration::from_millis(20),
+                false,
+            )
+            .expect("failed to emit throttled event");
+        }
+
+        let registry = app.state::<EventRegistry>();
+        let pending = || {
+            registry
+                .throttled
+                .lock()
+                .expect("Failed to lock EventRegistry")
+                .len()
+        };
+        assert_eq!(pending(), 1);
+        std::thread::sleep(Duration::from_millis(200));
+        assert_eq!(pending(), 0);
     }
 }
@@ -7,6 +7,7 @@ use crate::BuilderConfiguration;
 /// Currently implemented for:
 ///  - [`specta_typescript::Typescript`]
 ///  - [`specta_typescript::JSDoc`]
+///  - [`Permissions`](crate::Permissions)
 pub trait LanguageExt {
     /// The error type returned by the language's export function.
     type Error: error::Error + From<io::Error>;
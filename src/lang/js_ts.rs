@@ -1,4 +1,4 @@
-use std::{borrow::Cow, path::Path};
+use std::{borrow::Cow, collections::BTreeMap, path::Path};
 
 use heck::ToLowerCamelCase;
 use specta::{
@@ -12,7 +12,11 @@ use specta_typescript::{Error, Exporter, FrameworkExporter, Layout, define, sema
 use specta_util::Remapper;
 
 use crate::name::{resolve_tauri_command_name, resolve_tauri_event_name};
-use crate::{BuilderConfiguration, ErrorHandlingMode, LanguageExt};
+use crate::permissions::required_permission;
+use crate::{
+    BuilderConfiguration, ErrorHandlingMode, EventDirection, LanguageExt, RESPOND_COMMAND,
+    STICKY_COMMAND,
+};
 
 impl LanguageExt for specta_typescript::Typescript {
     type Error = Error;
@@ -29,19 +33,28 @@ impl LanguageExt for specta_typescript::Typescript {
                     exporter,
                     &cfg,
                     false,
-                    if cfg.typed_error_impl.is_empty() {
+                    if has_custom_typed_error_impl(&cfg) {
+                        &cfg.typed_error_impl
+                    } else {
                         match cfg.error_handling {
-                            ErrorHandlingMode::DataError => DATA_ERROR_IMPL_TS,
-                            ErrorHandlingMode::Throw | ErrorHandlingMode::Result => {
-                                TYPED_ERROR_IMPL_TS
+                            ErrorHandlingMode::Throw => COMMAND_ERROR_IMPL_TS,
+                            ErrorHandlingMode::Result if cfg.catch_panics => {
+                                TYPED_ERROR_PANIC_IMPL_TS
                             }
+                            ErrorHandlingMode::Result => TYPED_ERROR_IMPL_TS,
+                            ErrorHandlingMode::DataError => DATA_ERROR_IMPL_TS,
+                            ErrorHandlingMode::Effect => EFFECT_IMPL_TS,
+                            ErrorHandlingMode::Neverthrow => NEVERTHROW_IMPL_TS,
                         }
-                    } else {
-                        &cfg.typed_error_impl
                     },
-                    TYPED_ERROR_ASSERTION_TS,
-                    MAKE_EVENT_IMPL_TS,
-                    MAP_CHANNEL_IMPL_TS,
+                    match cfg.error_handling {
+                        ErrorHandlingMode::Effect => TYPED_ERROR_ASSERTION_EFFECT_TS,
+                        ErrorHandlingMode::Neverthrow => TYPED_ERROR_ASSERTION_NEVERTHROW_TS,
+                        ErrorHandlingMode::Throw
+                        | ErrorHandlingMode::Result
+                        | ErrorHandlingMode::DataError => TYPED_ERROR_ASSERTION_TS,
+                    },
+                    &RUNTIME_TS,
                 )
             })
             .export_to(path, &types, format)
@@ -63,36 +76,83 @@ impl LanguageExt for specta_typescript::JSDoc {
                     exporter,
                     &cfg,
                     true,
-                    if cfg.typed_error_impl.is_empty() {
+                    if has_custom_typed_error_impl(&cfg) {
+                        &cfg.typed_error_impl
+                    } else {
                         match cfg.error_handling {
-                            ErrorHandlingMode::DataError => DATA_ERROR_IMPL_JS,
-                            ErrorHandlingMode::Throw | ErrorHandlingMode::Result => {
-                                TYPED_ERROR_IMPL_JS
+                            ErrorHandlingMode::Throw => COMMAND_ERROR_IMPL_JS,
+                            ErrorHandlingMode::Result if cfg.catch_panics => {
+                                TYPED_ERROR_PANIC_IMPL_JS
                             }
+                            ErrorHandlingMode::Result => TYPED_ERROR_IMPL_JS,
+                            ErrorHandlingMode::DataError => DATA_ERROR_IMPL_JS,
+                            ErrorHandlingMode::Effect => EFFECT_IMPL_JS,
+                            ErrorHandlingMode::Neverthrow => NEVERTHROW_IMPL_JS,
                         }
-                    } else {
-                        &cfg.typed_error_impl
                     },
                     "",
-                    MAKE_EVENT_IMPL_JS,
-                    MAP_CHANNEL_IMPL_JS,
+                    &RUNTIME_JS,
                 )
             })
             .export_to(path, &types, format)
     }
 }
 
+/// The runtime implementations for each language.
+struct RuntimeImpls {
+    event_emit_types: &'static str,
+    make_event_impl: &'static str,
+    make_listen_event_impl: &'static str,
+    make_emit_event_impl: &'static str,
+    map_channel_impl: &'static str,
+    channel_stream_impl: &'static str,
+    command_panic_impl: &'static str,
+    command_rejected_impl: &'static str,
+    invalid_arguments_impl: &'static str,
+}
+
+const RUNTIME_TS: RuntimeImpls = RuntimeImpls {
+    event_emit_types: EVENT_EMIT_TYPES_TS,
+    make_event_impl: MAKE_EVENT_IMPL_TS,
+    make_listen_event_impl: MAKE_LISTEN_EVENT_IMPL_TS,
+    make_emit_event_impl: MAKE_EMIT_EVENT_IMPL_TS,
+    map_channel_impl: MAP_CHANNEL_IMPL_TS,
+    channel_stream_impl: CHANNEL_STREAM_IMPL_TS,
+    command_panic_impl: COMMAND_PANIC_IMPL_TS,
+    command_rejected_impl: COMMAND_REJECTED_IMPL_TS,
+    invalid_arguments_impl: INVALID_ARGUMENTS_IMPL_TS,
+};
+
+const RUNTIME_JS: RuntimeImpls = RuntimeImpls {
+    // JS has no types.
+    event_emit_types: "",
+    make_event_impl: MAKE_EVENT_IMPL_JS,
+    make_listen_event_impl: MAKE_LISTEN_EVENT_IMPL_JS,
+    make_emit_event_impl: MAKE_EMIT_EVENT_IMPL_JS,
+    map_channel_impl: MAP_CHANNEL_IMPL_JS,
+    channel_stream_impl: CHANNEL_STREAM_IMPL_JS,
+    command_panic_impl: COMMAND_PANIC_IMPL_JS,
+    command_rejected_impl: COMMAND_REJECTED_IMPL_JS,
+    invalid_arguments_impl: INVALID_ARGUMENTS_IMPL_JS,
+};
+
 fn runtime(
     mut exporter: FrameworkExporter,
     cfg: &BuilderConfiguration,
     jsdoc: bool,
     typed_error_impl: &str,
     typed_error_assertion: &str,
-    make_event_impl: &str,
-    map_channel_impl: &str,
+    impls: &RuntimeImpls,
 ) -> Result<Cow<'static, str>, Error> {
     let enabled_commands = !cfg.commands.is_empty();
-    let enabled_events = !cfg.events.is_empty();
+    // Only plugin commands are gated by permission identifiers.
+    let permissions_plugin_name = cfg.plugin_name.filter(|_| cfg.annotate_permissions);
+    let enabled_events = !cfg.event_definitions.is_empty();
+    let enabled_requests = !cfg.requests.is_empty();
+    let enabled_sticky_events = cfg
+        .event_definitions
+        .values()
+        .any(|event| event.sticky && event.direction != EventDirection::ToBackend);
     let semantic_types_runtime_types = semantic_types_runtime_types(cfg)?;
     let semantic_types_runtime_types = semantic_types_runtime_types
         .as_ref()
@@ -100,13 +160,38 @@ fn runtime(
 
     let mut out = String::new();
 
+    if cfg.annotate_permissions && enabled_commands && cfg.plugin_name.is_none() {
+        return Err(Error::framework(
+            "",
+            "Permissions can only be annotated for plugins, as app commands don't have permission identifiers. Set `Builder::plugin_name` or remove `Builder::annotate_permissions`.",
+        ));
+    }
+    if cfg.annotate_permissions && enabled_commands && cfg.constants.contains_key("permissions") {
+        return Err(Error::framework(
+            "",
+            "The constant 'permissions' must be renamed so it doesn't conflict with the exported command permissions.",
+        ));
+    }
+    if has_custom_typed_error_impl(cfg) && cfg.error_handling == ErrorHandlingMode::Throw {
+        return Err(Error::framework(
+            "",
+            "A custom `typedError` implementation can't be used in `ErrorHandlingMode::Throw`, which always throws a `CommandError`.",
+        ));
+    }
+    if enabled_requests && cfg.constants.contains_key("requests") {
+        return Err(Error::framework(
+            "",
+            "The constant 'requests' must be renamed so it doesn't conflict with the exported requests.",
+        ));
+    }
+
     if let Some((ndt, name)) = exporter
         .types
         .into_unsorted_iter()
         .filter(|ndt| ndt.ty.is_some())
         .find_map(|ndt| {
             runtime_scope_name(jsdoc, exporter.layout, ndt)
-                .filter(|name| RESERVED_NDT_NAMES.contains(&name.as_ref()))
+                .filter(|name| is_reserved_name(cfg, name))
                 .map(|name| (ndt, name))
         })
     {
@@ -143,8 +228,15 @@ fn runtime(
             })
         })
     });
+    let is_channel_stream_used = cfg.commands.iter().any(|command| {
+        command
+            .args()
+            .iter()
+            .filter(|(_, dt)| is_channel_type(dt, exporter.types))
+            .count()
+            == 1
+    });
     let has_typed_error = enabled_commands
-        && cfg.error_handling != ErrorHandlingMode::Throw
         && cfg.commands.iter().any(|command| {
             command
                 .result()
@@ -152,11 +244,18 @@ fn runtime(
                 .is_some()
         });
 
-    if enabled_commands || is_channel_used {
+    if enabled_commands || is_channel_used || enabled_sticky_events || enabled_requests {
         out.push_str("import { ");
 
         let imports = [
-            enabled_commands.then_some("invoke as __TAURI_INVOKE"),
+            // `__TAURI_INVOKE` may be a wrapper defined in the runtime.
+            (enabled_commands || enabled_sticky_events || enabled_requests).then_some(
+                if enabled_commands && has_invoke_wrapper(cfg) {
+                    "invoke as __TAURI_INVOKE_RAW"
+                } else {
+                    "invoke as __TAURI_INVOKE"
+                },
+            ),
             is_channel_used.then_some("Channel"),
         ];
 
@@ -171,9 +270,23 @@ fn runtime(
 
         out.push_str(" } from \"@tauri-apps/api/core\";\n");
     }
-    if enabled_events {
+    if enabled_events || enabled_requests {
         out.push_str("import * as __TAURI_EVENT from \"@tauri-apps/api/event\";\n");
     }
+    if enabled_requests {
+        out.push_str("import * as __TAURI_WEBVIEW from \"@tauri-apps/api/webview\";\n");
+    }
+    // Effect and neverthrow wrap every command, not only those which return a `Result`.
+    if enabled_commands {
+        match cfg.error_handling {
+            ErrorHandlingMode::Effect => out.push_str("import { Effect } from \"effect\";\n"),
+            ErrorHandlingMode::Neverthrow => {
+                out.push_str("import { ResultAsync } from \"neverthrow\";\n")
+            }
+            ErrorHandlingMode::Throw | ErrorHandlingMode::Result | ErrorHandlingMode::DataError => {
+            }
+        }
+    }
 
     // Commands
     if enabled_commands {
@@ -201,18 +314,21 @@ fn runtime(
                 })
                 .collect::<Result<Vec<_>, Error>>()?;
 
-            let fn_arguments = arguments
-                .iter()
-                .map(|(name, dt)| {
-                    let mut arg = name.to_string();
-                    if !jsdoc {
-                        arg.push_str(": ");
-                        arg.push_str(dt);
-                    }
-                    arg
-                })
-                .collect::<Vec<_>>()
-                .join(", ");
+            let render_fn_arguments = |arguments: &[&(String, String)]| {
+                arguments
+                    .iter()
+                    .map(|(name, dt)| {
+                        let mut arg = name.to_string();
+                        if !jsdoc {
+                            arg.push_str(": ");
+                            arg.push_str(dt);
+                        }
+                        arg
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let fn_arguments = render_fn_arguments(&arguments.iter().collect::<Vec<_>>());
 
             let arguments_invoke_obj = if command.args().is_empty() {
                 Default::default()
@@ -258,8 +374,7 @@ fn runtime(
 
             let invoke_args = format!("({command_name_escaped}{arguments_invoke_obj})",);
 
-            let body = if cfg.error_handling != ErrorHandlingMode::Throw
-                && let Some(result) = command.result()
+            let body = if let Some(result) = command.result()
                 && let Some((dt_ok, dt_err)) = extract_std_result(result, exporter.types)
             {
                 let semantic_err_type = apply_semantic_type_for_phase(
@@ -298,24 +413,43 @@ fn runtime(
                     cfg,
                     semantic_types_runtime_types,
                 );
+                // Effect and neverthrow map the success and error values separately.
+                let (ok_input, err_input) = match cfg.error_handling {
+                    ErrorHandlingMode::Throw
+                    | ErrorHandlingMode::Effect
+                    | ErrorHandlingMode::Neverthrow => ("v", "v"),
+                    ErrorHandlingMode::Result | ErrorHandlingMode::DataError => {
+                        ("v.data", "v.error")
+                    }
+                };
                 let ok_transform = render_result_transform_for_phase(
                     dt_ok,
                     Phase::Deserialize,
-                    "v.data",
+                    ok_input,
                     &exporter,
                     cfg,
                     semantic_types_runtime_types,
                 )
-                .map(|transform| jsdoc_transform(transform, "v.data", jsdoc));
+                .map(|transform| jsdoc_transform(transform, ok_input, jsdoc));
                 let err_transform = render_result_transform_for_phase(
                     dt_err,
                     Phase::Deserialize,
-                    "v.error",
+                    err_input,
                     &exporter,
                     cfg,
                     semantic_types_runtime_types,
                 )
-                .map(|transform| jsdoc_transform(transform, "v.error", jsdoc));
+                .map(|transform| jsdoc_transform(transform, err_input, jsdoc))
+                // `InvalidArguments` isn't a command error so it must not be transformed.
+                .map(|transform| {
+                    if cfg.typed_argument_errors {
+                        format!(
+                            "({err_input} instanceof InvalidArguments ? {err_input} : {transform})"
+                        )
+                    } else {
+                        transform
+                    }
+                });
 
                 let mut invoke_ts = "typedError".to_string();
                 if !jsdoc {
@@ -345,14 +479,71 @@ fn runtime(
                         cfg,
                         semantic_types_runtime_types,
                     )?);
+                    if cfg.typed_argument_errors {
+                        invoke_ts.push_str(" | InvalidArguments");
+                    }
+                    if cfg.error_handling == ErrorHandlingMode::Throw {
+                        invoke_ts.push_str(", ");
+                        invoke_ts.push_str(
+                            &serde_json::to_string(command.name())
+                                .expect("failed to serialize string"),
+                        );
+                    }
                     invoke_ts.push('>');
                 }
-                invoke_ts.push_str("(__TAURI_INVOKE");
+                match cfg.error_handling {
+                    ErrorHandlingMode::Throw => {
+                        invoke_ts.push('(');
+                        invoke_ts.push_str(
+                            &serde_json::to_string(command.name())
+                                .expect("failed to serialize string"),
+                        );
+                        invoke_ts.push_str(", __TAURI_INVOKE");
+                    }
+                    // Effects are lazy so the command is only invoked once the effect is run.
+                    ErrorHandlingMode::Effect => invoke_ts.push_str("(() => __TAURI_INVOKE"),
+                    ErrorHandlingMode::Result
+                    | ErrorHandlingMode::DataError
+                    | ErrorHandlingMode::Neverthrow => invoke_ts.push_str("(__TAURI_INVOKE"),
+                }
                 invoke_ts.push_str(&invoke_args);
+                if cfg.error_handling == ErrorHandlingMode::Throw
+                    && let Some(transform) = &err_transform
+                {
+                    invoke_ts.push_str(&format!(", (v) => {transform}"));
+                }
                 invoke_ts.push(')');
 
                 if ok_transform.is_none() && err_transform.is_none() {
                     invoke_ts
+                } else if cfg.error_handling == ErrorHandlingMode::Throw {
+                    match ok_transform {
+                        Some(transform) if jsdoc => format!("{invoke_ts}.then((v) => {transform})"),
+                        Some(transform) => {
+                            format!("{invoke_ts}.then((v) => ({transform} as typeof v))")
+                        }
+                        None => invoke_ts,
+                    }
+                } else if cfg.error_handling == ErrorHandlingMode::Effect {
+                    let mappers = [
+                        ok_transform.map(|transform| format!("Effect.map((v) => {transform})")),
+                        err_transform
+                            .map(|transform| format!("Effect.mapError((v) => {transform})")),
+                    ];
+                    format!(
+                        "{invoke_ts}.pipe({})",
+                        mappers.into_iter().flatten().collect::<Vec<_>>().join(", ")
+                    )
+                } else if cfg.error_handling == ErrorHandlingMode::Neverthrow {
+                    format!(
+                        "{invoke_ts}{}{}",
+                        ok_transform
+                            .map(|transform| format!(".map((v) => {transform})"))
+                            .unwrap_or_default(),
+                        err_transform
+                            .map(|transform| format!(".mapErr((v) => {transform})"))
+                            .unwrap_or_default(),
+                    )
                 } else {
                     let mapper = result_mapper(cfg.error_handling, ok_transform, err_transform);
 
@@ -402,7 +593,7 @@ fn runtime(
                 }
                 invoke_ts.push_str(&invoke_args);
 
-                if let Some(dt) = output_dt
+                let invoke_ts = if let Some(dt) = output_dt
                     && let Some(mapped) = render_result_transform_for_phase(
                         dt,
                         Phase::Deserialize,
@@ -420,14 +611,60 @@ fn runtime(
                     }
                 } else {
                     invoke_ts
+                };
+
+                // Commands which can't fail still return the library's type, with any rejection as a defect.
+                match cfg.error_handling {
+                    ErrorHandlingMode::Effect => format!("Effect.promise(() => {invoke_ts})"),
+                    ErrorHandlingMode::Neverthrow => {
+                        format!("ResultAsync.fromSafePromise({invoke_ts})")
+                    }
+                    ErrorHandlingMode::Throw
+                    | ErrorHandlingMode::Result
+                    | ErrorHandlingMode::DataError => invoke_ts,
                 }
             };
 
-            let mut field = Field::new(define(format!("({fn_arguments}) => {body}")).into());
+            let mut command_fn = format!("({fn_arguments}) => {body}");
+            if let [(channel, _)] = command
+                .args()
+                .iter()
+                .filter(|(_, dt)| is_channel_type(dt, exporter.types))
+                .collect::<Vec<_>>()
+                .as_slice()
+            {
+                let channel = channel.to_lower_camel_case();
+                let (channel_arguments, stream_arguments): (Vec<_>, Vec<_>) =
+                    arguments.iter().partition(|(name, _)| *name == channel);
+                // The stream's result must be a promise so effects are run to their `Exit`.
+                let body = if cfg.error_handling == ErrorHandlingMode::Effect {
+                    Cow::Owned(format!("Effect.runPromiseExit({body})"))
+                } else {
+                    Cow::Borrowed(body.as_str())
+                };
+
+                command_fn = format!(
+                    "Object.assign({command_fn}, {{ stream: ({}) => channelStream(({}) => {body}) }})",
+                    render_fn_arguments(&stream_arguments),
+                    render_fn_arguments(&channel_arguments),
+                );
+            }
+
+            let mut field = Field::new(define(command_fn).into());
             field.deprecated = command.deprecated.clone();
             field.docs = {
                 let mut docs = command.docs.to_string();
 
+                if let Some(plugin_name) = permissions_plugin_name {
+                    if !docs.is_empty() {
+                        docs.push('\n');
+                    }
+                    docs.push_str(&format!(
+                        "Requires the `{}` permission.",
+                        required_permission(plugin_name, command.name())
+                    ));
+                }
+
                 if jsdoc {
                     if !docs.is_empty() {
                         docs.push('\n');
@@ -445,8 +682,7 @@ fn runtime(
                         docs.push('\n');
                     }
 
-                    let returns = if cfg.error_handling != ErrorHandlingMode::Throw
-                        && let Some(result) = command.result()
+                    let returns = if let Some(result) = command.result()
                         && let Some((dt_ok, dt_err)) = extract_std_result(result, exporter.types)
                     {
                         let ok_semantic_type = has_semantic_type_for_phase(
@@ -477,7 +713,7 @@ fn runtime(
                             cfg,
                             semantic_types_runtime_types,
                         )?;
-                        let err = render_reference_dt_for_phase(
+                        let mut err = render_reference_dt_for_phase(
                             dt_err,
                             if err_semantic_type {
                                 Phase::Deserialize
@@ -489,8 +725,19 @@ fn runtime(
                             cfg,
                             semantic_types_runtime_types,
                         )?;
+                        if cfg.typed_argument_errors {
+                            err.push_str(" | InvalidArguments");
+                        }
 
-                        result_type(cfg.error_handling, &ok, &err)
+                        if cfg.error_handling == ErrorHandlingMode::Throw {
+                            docs.push_str(&format!(
+                                "@throws {{CommandError<{}, {err}>}}\n",
+                                serde_json::to_string(command.name())
+                                    .expect("failed to serialize string")
+                            ));
+                        }
+
+                        result_type(cfg, &ok, &err)
                     } else {
                         let output_dt = command
                             .result()
@@ -507,8 +754,8 @@ fn runtime(
                             )
                         });
 
-                        match output_dt {
-                            Some(dt) => render_reference_dt_for_phase(
+                        let ok = match output_dt {
+                            Some(dt) => Cow::Owned(render_reference_dt_for_phase(
                                 dt,
                                 if output_semantic_type {
                                     Phase::Deserialize
@@ -519,12 +766,14 @@ fn runtime(
                                 &exporter,
                                 cfg,
                                 semantic_types_runtime_types,
-                            )?,
-                            None => "void".to_string(),
-                        }
+                            )?),
+                            None => Cow::Borrowed("void"),
+                        };
+
+                        infallible_result_type(cfg, &ok)
                     };
 
-                    docs.push_str(&format!("@returns {{Promise<{returns}>}}"));
+                    docs.push_str(&format!("@returns {{{returns}}}"));
                 }
 
                 docs.into()
@@ -539,96 +788,303 @@ fn runtime(
         out.push_str("\nexport const commands = ");
         out.push_str(&exporter.reference(&s.build())?);
         out.push_str(";\n");
+
+        if let Some(plugin_name) = permissions_plugin_name {
+            out.push_str("\n/** The permission required to call each command */");
+            out.push_str("\nexport const permissions = ");
+            // Rendered like `commands` so the keys are quoted when they aren't valid identifiers.
+            let permissions = cfg.commands.iter().fold(Struct::named(), |s, command| {
+                s.field(
+                    cfg.function_casing.apply(command.name()).into_owned(),
+                    Field::new(
+                        define(
+                            serde_json::to_string(&required_permission(
+                                plugin_name,
+                                command.name(),
+                            ))
+                            .expect("failed to serialize string"),
+                        )
+                        .into(),
+                    ),
+                )
+            });
+            out.push_str(&exporter.reference(&permissions.build())?);
+            out.push_str(if jsdoc { ";\n" } else { " as const;\n" });
+        }
+    }
+
+    // Window labels
+    if !cfg.window_labels.is_empty() {
+        let mut labels = Vec::<&str>::new();
+        for label in &cfg.window_labels {
+            if !labels.contains(&label.as_ref()) {
+                labels.push(label);
+            }
+        }
+        let union = labels
+            .iter()
+            .map(|label| serde_json::to_string(label).expect("failed to serialize string"))
+            .collect::<Vec<_>>()
+            .join(" | ");
+
+        out.push_str("\n/** Window labels */");
+        if jsdoc {
+            out.push_str(&format!("\n/** @typedef {{{union}}} WindowLabel */\n"));
+        } else {
+            out.push_str(&format!("\nexport type WindowLabel = {union};\n"));
+        }
     }
 
     // Events
     if enabled_events {
         let mut s = Struct::named();
-        for (name, (_, r)) in &cfg.events {
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for (name, event) in &cfg.event_definitions {
+            let event_name = resolve_tauri_event_name(cfg.plugin_name, name);
             let event_name_escaped =
-                serde_json::to_string(&resolve_tauri_event_name(cfg.plugin_name, name))
-                    .expect("failed to serialize string");
+                serde_json::to_string(&event_name).expect("failed to serialize string");
+
+            let event_dt = event.data_type.clone();
+            // The frontend only listens to events sent to it and only emits events sent to the backend.
+            let (listens, emits) = match event.direction {
+                EventDirection::ToFrontend => (true, false),
+                EventDirection::ToBackend => (false, true),
+                EventDirection::Both => (true, true),
+            };
+            let make_event = match event.direction {
+                EventDirection::ToFrontend => "makeListenEvent",
+                EventDirection::ToBackend => "makeEmitEvent",
+                EventDirection::Both => "makeEvent",
+            };
+            let labels = if cfg.window_labels.is_empty() || !emits {
+                ""
+            } else {
+                ", WindowLabel"
+            };
 
-            let event_dt = DataType::Reference(r.clone());
-            let mut field_ts = "makeEvent".to_string();
+            let mut field_ts = make_event.to_string();
             if !jsdoc {
-                field_ts.push('<');
-                field_ts.push_str(&render_reference_dt_for_phase(
-                    &event_dt,
-                    Phase::Serialize,
-                    Phase::Deserialize,
-                    &exporter,
-                    cfg,
-                    semantic_types_runtime_types,
-                )?);
-                field_ts.push_str(", ");
-                field_ts.push_str(&render_reference_dt_for_phase(
-                    &event_dt,
-                    Phase::Deserialize,
-                    Phase::Serialize,
-                    &exporter,
-                    cfg,
-                    semantic_types_runtime_types,
-                )?);
-                field_ts.push('>');
+                let mut generics = Vec::new();
+                if listens {
+                    generics.push(render_reference_dt_for_phase(
+                        &event_dt,
+                        Phase::Serialize,
+                        Phase::Deserialize,
+                        &exporter,
+                        cfg,
+                        semantic_types_runtime_types,
+                    )?);
+                }
+                if emits {
+                    generics.push(render_reference_dt_for_phase(
+                        &event_dt,
+                        Phase::Deserialize,
+                        Phase::Serialize,
+                        &exporter,
+                        cfg,
+                        semantic_types_runtime_types,
+                    )?);
+                }
+                field_ts.push_str(&format!("<{}{labels}>", generics.join(", ")));
             }
             field_ts.push('(');
             field_ts.push_str(&event_name_escaped);
-            let serialize_transform = render_result_transform_for_phase(
-                &event_dt,
+            let serialize_transform = emits
+                .then(|| {
+                    render_result_transform_for_phase(
+                        &event_dt,
+                        Phase::Serialize,
+                        "v",
+                        &exporter,
+                        cfg,
+                        semantic_types_runtime_types,
+                    )
+                })
+                .flatten()
+                .map(|transform| jsdoc_transform(transform, "v", jsdoc));
+            let deserialize_transform = listens
+                .then(|| {
+                    render_result_transform_for_phase(
+                        &event_dt,
+                        Phase::Deserialize,
+                        "v",
+                        &exporter,
+                        cfg,
+                        semantic_types_runtime_types,
+                    )
+                })
+                .flatten()
+                .map(|transform| jsdoc_transform(transform, "v", jsdoc));
+            let sticky = event.sticky && listens;
+            let sticky_transform = sticky.then(|| deserialize_transform.clone()).flatten();
+
+            let transforms = match event.direction {
+                EventDirection::ToFrontend => vec![deserialize_transform],
+                EventDirection::ToBackend => vec![serialize_transform],
+                EventDirection::Both => vec![serialize_transform, deserialize_transform],
+            };
+            if transforms.iter().any(Option::is_some) {
+                for transform in transforms {
+                    field_ts.push_str(", ");
+                    field_ts.push_str(
+                        &transform
+                            .map(|transform| format!("(v) => {transform}"))
+                            .unwrap_or_else(|| "undefined".to_string()),
+                    );
+                }
+            }
+            field_ts.push(')');
+            if sticky {
+                field_ts = format!(
+                    "makeStickyEvent({event_name_escaped}, {field_ts}{})",
+                    sticky_transform
+                        .map(|transform| format!(", (v) => {transform}"))
+                        .unwrap_or_default()
+                );
+            }
+
+            let mut field = Field::new(define(field_ts).into());
+            let mut docs = event.docs.to_string();
+            if !docs.is_empty() {
+                docs.push('\n');
+            }
+            docs.push_str(&format!("Tauri event: `{event_name}`"));
+            field.deprecated = event.deprecated.clone();
+            if jsdoc {
+                let (sticky_start, sticky_end) = if sticky {
+                    ("ReturnType<typeof makeStickyEvent<", ">>")
+                } else {
+                    ("", "")
+                };
+                docs.push_str(&format!(
+                    "\n@type {{{sticky_start}ReturnType<typeof {make_event}<{}{labels}>>{sticky_end}}}",
+                    if listens {
+                        render_reference_dt_for_phase(
+                            &event_dt,
+                            Phase::Deserialize,
+                            Phase::Deserialize,
+                            &exporter,
+                            cfg,
+                            semantic_types_runtime_types,
+                        )?
+                    } else {
+                        render_reference_dt_for_phase(
+                            &event_dt,
+                            Phase::Deserialize,
+                            Phase::Serialize,
+                            &exporter,
+                            cfg,
+                            semantic_types_runtime_types,
+                        )?
+                    },
+                ));
+            }
+            field.docs = docs.into();
+            match event.group {
+                // Events in a group are nested in an object by the name of the event without the group's prefix.
+                Some(group) => groups.entry(group).or_default().push((
+                    cfg.function_casing
+                        .apply(&name[group.len() + 1..])
+                        .into_owned(),
+                    field,
+                )),
+                None => s = s.field(cfg.function_casing.apply(name).into_owned(), field),
+            }
+        }
+        for (group, fields) in groups {
+            let group_s = fields
+                .into_iter()
+                .fold(Struct::named(), |group_s, (name, field)| {
+                    group_s.field(name, field)
+                });
+            s = s.field(
+                cfg.function_casing.apply(group).into_owned(),
+                Field::new(group_s.build()),
+            );
+        }
+
+        out.push_str("\n/** Events */");
+        out.push_str("\nexport const events = ");
+        out.push_str(&exporter.reference(&s.build())?);
+        out.push_str(";\n");
+    }
+
+    // Requests
+    if enabled_requests {
+        let mut s = Struct::named();
+        for (name, request) in &cfg.requests {
+            let event_name = resolve_tauri_event_name(cfg.plugin_name, name);
+            let event_name_escaped =
+                serde_json::to_string(&event_name).expect("failed to serialize string");
+
+            // The frontend receives the request and sends the response.
+            let request_ts = render_reference_dt_for_phase(
+                &request.request,
                 Phase::Serialize,
-                "v",
+                Phase::Deserialize,
                 &exporter,
                 cfg,
                 semantic_types_runtime_types,
-            )
-            .map(|transform| jsdoc_transform(transform, "v", jsdoc));
-            let deserialize_transform = render_result_transform_for_phase(
-                &event_dt,
+            )?;
+            let response_ts = render_reference_dt_for_phase(
+                &request.response,
                 Phase::Deserialize,
-                "v",
+                Phase::Serialize,
                 &exporter,
                 cfg,
                 semantic_types_runtime_types,
-            )
-            .map(|transform| jsdoc_transform(transform, "v", jsdoc));
-
-            if serialize_transform.is_some() || deserialize_transform.is_some() {
-                field_ts.push_str(", ");
-                field_ts.push_str(
-                    &serialize_transform
-                        .map(|transform| format!("(v) => {transform}"))
-                        .unwrap_or_else(|| "undefined".to_string()),
-                );
-                field_ts.push_str(", ");
-                field_ts.push_str(
-                    &deserialize_transform
-                        .map(|transform| format!("(v) => {transform}"))
-                        .unwrap_or_else(|| "undefined".to_string()),
-                );
+            )?;
+            let transforms = [
+                (&request.request, Phase::Deserialize),
+                (&request.response, Phase::Serialize),
+            ]
+            .map(|(dt, phase)| {
+                render_result_transform_for_phase(
+                    dt,
+                    phase,
+                    "v",
+                    &exporter,
+                    cfg,
+                    semantic_types_runtime_types,
+                )
+                .map(|transform| jsdoc_transform(transform, "v", jsdoc))
+            });
+
+            let mut field_ts = "makeRequest".to_string();
+            if !jsdoc {
+                field_ts.push_str(&format!("<{request_ts}, {response_ts}>"));
+            }
+            field_ts.push('(');
+            field_ts.push_str(&event_name_escaped);
+            if transforms.iter().any(Option::is_some) {
+                for transform in transforms {
+                    field_ts.push_str(", ");
+                    field_ts.push_str(
+                        &transform
+                            .map(|transform| format!("(v) => {transform}"))
+                            .unwrap_or_else(|| "undefined".to_string()),
+                    );
+                }
             }
             field_ts.push(')');
 
             let mut field = Field::new(define(field_ts).into());
+            let mut docs = request.docs.to_string();
+            if !docs.is_empty() {
+                docs.push('\n');
+            }
+            docs.push_str(&format!("Tauri event: `{event_name}`"));
             if jsdoc {
-                field.docs = format!(
-                    "@type {{ReturnType<typeof makeEvent<{}>>}}",
-                    render_reference_dt_for_phase(
-                        &DataType::Reference(r.clone()),
-                        Phase::Deserialize,
-                        Phase::Deserialize,
-                        &exporter,
-                        cfg,
-                        semantic_types_runtime_types,
-                    )?
-                )
-                .into();
+                docs.push_str(&format!(
+                    "\n@type {{ReturnType<typeof makeRequest<{request_ts}, {response_ts}>>}}"
+                ));
             }
+            field.docs = docs.into();
             s = s.field(cfg.function_casing.apply(name).into_owned(), field);
         }
 
-        out.push_str("\n/** Events */");
-        out.push_str("\nexport const events = ");
+        out.push_str("\n/** Requests */");
+        out.push_str("\nexport const requests = ");
         out.push_str(&exporter.reference(&s.build())?);
         out.push_str(";\n");
     }
@@ -676,35 +1132,64 @@ fn runtime(
     }
 
     // Runtime
-    if has_typed_error || enabled_events || is_channel_transform_used {
-        out.push_str("\n/* Tauri Specta runtime */\n");
-
-        if is_channel_transform_used {
-            out.push_str(map_channel_impl);
-            out.push('\n');
-            if has_typed_error || enabled_events {
-                out.push('\n');
-            }
-        }
-        if has_typed_error {
-            out.push_str(typed_error_impl);
-            out.push('\n');
-            // We check against `cfg` not `typed_error_assertion` as we only include the assertion if the user-provides an impl.
-            // It's assumed the internal one is correct.
-            if !cfg.typed_error_impl.is_empty() {
-                out.push('\n');
-                out.push_str(typed_error_assertion);
-                out.push('\n');
-            }
-            if enabled_events {
-                out.push('\n');
-            }
-        }
-        if enabled_events {
-            out.push_str(make_event_impl);
-            out.push('\n');
+    let mut runtime = Vec::<Cow<'_, str>>::new();
+    if is_channel_transform_used {
+        runtime.push(impls.map_channel_impl.into());
+    }
+    if is_channel_stream_used {
+        runtime.push(impls.channel_stream_impl.into());
+    }
+    if enabled_commands && cfg.catch_panics {
+        runtime.push(impls.command_panic_impl.into());
+    }
+    if enabled_commands && cfg.has_middleware {
+        runtime.push(impls.command_rejected_impl.into());
+    }
+    if enabled_commands && cfg.typed_argument_errors {
+        runtime.push(impls.invalid_arguments_impl.into());
+    }
+    if enabled_commands && has_invoke_wrapper(cfg) {
+        runtime.push(invoke_wrapper(cfg, jsdoc).into());
+    }
+    if has_typed_error {
+        // We check against `cfg` not `typed_error_assertion` as we only include the assertion if the user-provides an impl.
+        // It's assumed the internal one is correct.
+        if has_custom_typed_error_impl(cfg) {
+            runtime.push(format!("{typed_error_impl}\n\n{typed_error_assertion}").into());
+        } else {
+            runtime.push(typed_error_impl.into());
         }
     }
+    let has_direction = |direction| {
+        cfg.event_definitions
+            .values()
+            .any(|event| event.direction == direction)
+    };
+    if (has_direction(EventDirection::Both) || has_direction(EventDirection::ToBackend))
+        && !impls.event_emit_types.is_empty()
+    {
+        runtime.push(impls.event_emit_types.into());
+    }
+    if has_direction(EventDirection::Both) {
+        runtime.push(impls.make_event_impl.into());
+    }
+    if has_direction(EventDirection::ToFrontend) {
+        runtime.push(impls.make_listen_event_impl.into());
+    }
+    if has_direction(EventDirection::ToBackend) {
+        runtime.push(impls.make_emit_event_impl.into());
+    }
+    if enabled_sticky_events {
+        runtime.push(make_sticky_event_impl(cfg, jsdoc).into());
+    }
+    if enabled_requests {
+        runtime.push(make_request_impl(cfg, jsdoc).into());
+    }
+    if !runtime.is_empty() {
+        out.push_str("\n/* Tauri Specta runtime */\n");
+        out.push_str(&runtime.join("\n\n"));
+        out.push('\n');
+    }
 
     Ok(Cow::Owned(out))
 }
@@ -831,9 +1316,9 @@ fn hide_unused_std_result_type(cfg: &BuilderConfiguration, mut types: Types) ->
                 }
             })
     }) || cfg
-        .events
+        .event_definitions
         .values()
-        .any(|(_, r)| datatype_contains_std_result(&DataType::Reference(r.clone()), &types))
+        .any(|event| datatype_contains_std_result(&event.data_type, &types))
         || types.into_unsorted_iter().any(|ndt| {
             if is_result_ndt(ndt) {
                 false
@@ -1061,18 +1546,39 @@ fn result_mapper(
         (ErrorHandlingMode::DataError, None, Some(err)) => {
             format!("(v.error !== null ? {{ ...v, error: {err} }} : v)")
         }
-        (ErrorHandlingMode::Throw, _, _) | (_, None, None) => "v".to_string(),
+        (
+            ErrorHandlingMode::Throw | ErrorHandlingMode::Effect | ErrorHandlingMode::Neverthrow,
+            _,
+            _,
+        )
+        | (_, None, None) => "v".to_string(),
     }
 }
 
-fn result_type(mode: ErrorHandlingMode, ok: &str, err: &str) -> String {
-    match mode {
-        ErrorHandlingMode::Throw => ok.to_string(),
-        ErrorHandlingMode::Result => {
-            format!("{{ status: \"ok\"; data: {ok} }} | {{ status: \"error\"; error: {err} }}")
-        }
+fn result_type(cfg: &BuilderConfiguration, ok: &str, err: &str) -> String {
+    match cfg.error_handling {
+        ErrorHandlingMode::Throw => format!("Promise<{ok}>"),
+        ErrorHandlingMode::Result if cfg.catch_panics => format!(
+            "Promise<{{ status: \"ok\"; data: {ok} }} | {{ status: \"error\"; error: {err} }} | {{ status: \"panic\"; message: string }}>"
+        ),
+        ErrorHandlingMode::Result => format!(
+            "Promise<{{ status: \"ok\"; data: {ok} }} | {{ status: \"error\"; error: {err} }}>"
+        ),
         ErrorHandlingMode::DataError => {
-            format!("{{ data: {ok}; error: null }} | {{ data: null; error: {err} }}")
+            format!("Promise<{{ data: {ok}; error: null }} | {{ data: null; error: {err} }}>")
+        }
+        ErrorHandlingMode::Effect => format!("Effect.Effect<{ok}, {err}>"),
+        ErrorHandlingMode::Neverthrow => format!("ResultAsync<{ok}, {err}>"),
+    }
+}
+
+/// The type returned by a command which doesn't return a [`Result`].
+fn infallible_result_type(cfg: &BuilderConfiguration, ok: &str) -> String {
+    match cfg.error_handling {
+        ErrorHandlingMode::Effect => format!("Effect.Effect<{ok}, never>"),
+        ErrorHandlingMode::Neverthrow => format!("ResultAsync<{ok}, never>"),
+        ErrorHandlingMode::Throw | ErrorHandlingMode::Result | ErrorHandlingMode::DataError => {
+            format!("Promise<{ok}>")
         }
     }
 }
@@ -1163,18 +1669,292 @@ fn render_reference_dt(dt: &DataType, exporter: &FrameworkExporter) -> Result<St
     }
 }
 
-const RESERVED_NDT_NAMES: &[&str] = &[
+fn has_custom_typed_error_impl(cfg: &BuilderConfiguration) -> bool {
+    !cfg.typed_error_impl.is_empty()
+}
+
+fn has_invoke_wrapper(cfg: &BuilderConfiguration) -> bool {
+    cfg.catch_panics || cfg.typed_argument_errors || cfg.has_middleware
+}
+
+/// The wrapper around Tauri's `invoke` which converts the errors of the enabled features.
+fn invoke_wrapper(cfg: &BuilderConfiguration, jsdoc: bool) -> String {
+    let mut body = String::new();
+    if cfg.catch_panics {
+        body.push_str("        if (typeof e === \"object\" && e !== null && \"__tauriSpectaPanic\" in e) throw new CommandPanic(String(e.__tauriSpectaPanic));\n");
+    }
+    if cfg.has_middleware {
+        body.push_str("        if (typeof e === \"object\" && e !== null && \"__tauriSpectaRejected\" in e) throw new CommandRejected(String(e.__tauriSpectaRejected));\n");
+    }
+    body.push_str(if cfg.typed_argument_errors {
+        "        throw invalidArguments(e);"
+    } else {
+        "        throw e;"
+    });
+    if jsdoc {
+        format!(
+            r#"/**
+ * @template T
+ * @param {{Parameters<typeof __TAURI_INVOKE_RAW>}} args
+ * @returns {{Promise<T>}}
+ */
+function __TAURI_INVOKE(...args) {{
+    return __TAURI_INVOKE_RAW(...args).catch((e) => {{
+{body}
+    }});
+}}"#
+        )
+    } else {
+        format!(
+            r#"function __TAURI_INVOKE<T>(...args: Parameters<typeof __TAURI_INVOKE_RAW>): Promise<T> {{
+    return __TAURI_INVOKE_RAW<T>(...args).catch((e) => {{
+{body}
+    }});
+}}"#
+        )
+    }
+}
+
+/// The invoke function used for the hidden commands.
+fn hidden_command_invoke(cfg: &BuilderConfiguration) -> &'static str {
+    // The hidden commands can't panic or be passed invalid arguments so we only need the wrapper for middleware rejections.
+    if !cfg.commands.is_empty() && has_invoke_wrapper(cfg) && !cfg.has_middleware {
+        "__TAURI_INVOKE_RAW"
+    } else {
+        "__TAURI_INVOKE"
+    }
+}
+
+/// The wrapper which adds `current()` and `listenWithCurrent()` to sticky events.
+fn make_sticky_event_impl(cfg: &BuilderConfiguration, jsdoc: bool) -> String {
+    let invoke = hidden_command_invoke(cfg);
+    let command =
+        serde_json::to_string(&resolve_tauri_command_name(cfg.plugin_name, STICKY_COMMAND))
+            .expect("failed to serialize string");
+
+    if jsdoc {
+        format!(
+            r#"/**
+ * @template {{{{ listen: (cb: __TAURI_EVENT.EventCallback<any>) => Promise<__TAURI_EVENT.UnlistenFn> }}}} E
+ * @param {{string}} name
+ * @param {{E}} event
+ * @param {{(payload: any) => unknown}} [deserialize]
+ */
+function makeStickyEvent(name, event, deserialize) {{
+    /** @returns {{Promise<(E extends {{ listen: (cb: __TAURI_EVENT.EventCallback<infer T>) => any }} ? T : never) | undefined>}} */
+    const current = async () => {{
+        const cached = /** @type {{{{ payload: unknown }} | null}} */ (await {invoke}({command}, {{ event: name }}));
+        if (cached === null) return undefined;
+        return /** @type {{any}} */ (deserialize ? deserialize(cached.payload) : cached.payload);
+    }};
+
+    /** @param {{(payload: E extends {{ listen: (cb: __TAURI_EVENT.EventCallback<infer T>) => any }} ? T : never) => void}} cb */
+    const listenWithCurrent = async (cb) => {{
+        let live = false;
+        const unlisten = await event.listen((e) => {{
+            live = true;
+            cb(e.payload);
+        }});
+        try {{
+            const payload = await current();
+            // A payload received while fetching the current one is newer.
+            if (!live && payload !== undefined) cb(payload);
+        }} catch (e) {{
+            unlisten();
+            throw e;
+        }}
+        return unlisten;
+    }};
+
+    return Object.assign(event, {{ current, listenWithCurrent }});
+}}"#
+        )
+    } else {
+        format!(
+            r#"function makeStickyEvent<TEvent extends {{ listen: (cb: __TAURI_EVENT.EventCallback<any>) => Promise<__TAURI_EVENT.UnlistenFn> }}>(name: string, event: TEvent, deserialize?: (payload: any) => unknown) {{
+    type T = TEvent extends {{ listen: (cb: __TAURI_EVENT.EventCallback<infer U>) => any }} ? U : never;
+
+    const current = async (): Promise<T | undefined> => {{
+        const cached = await {invoke}<{{ payload: unknown }} | null>({command}, {{ event: name }});
+        if (cached === null) return undefined;
+        return (deserialize ? deserialize(cached.payload) : cached.payload) as T;
+    }};
+
+    const listenWithCurrent = async (cb: (payload: T) => void) => {{
+        let live = false;
+        const unlisten = await event.listen((e) => {{
+            live = true;
+            cb(e.payload);
+        }});
+        try {{
+            const payload = await current();
+            // A payload received while fetching the current one is newer.
+            if (!live && payload !== undefined) cb(payload);
+        }} catch (e) {{
+            unlisten();
+            throw e;
+        }}
+        return unlisten;
+    }};
+
+    return Object.assign(event, {{ current, listenWithCurrent }});
+}}"#
+        )
+    }
+}
+
+/// The implementation of `requests.myRequest.handle(cb)`.
+fn make_request_impl(cfg: &BuilderConfiguration, jsdoc: bool) -> String {
+    let invoke = hidden_command_invoke(cfg);
+    let command = serde_json::to_string(&resolve_tauri_command_name(
+        cfg.plugin_name,
+        RESPOND_COMMAND,
+    ))
+    .expect("failed to serialize string");
+
+    if jsdoc {
+        format!(
+            r#"/**
+ * @template TRequest
+ * @template TResponse
+ * @param {{string}} name
+ * @param {{(payload: any) => TRequest}} [deserialize]
+ * @param {{(response: TResponse) => unknown}} [serialize]
+ */
+function makeRequest(name, deserialize, serialize) {{
+    /** @param {{{{ id: string, response?: unknown, error?: string }}}} response */
+    const respond = (response) => {invoke}({command}, response);
+
+    return {{
+        // Only the requests sent to this webview are handled.
+        /** @param {{(request: TRequest) => TResponse | Promise<TResponse>}} handler */
+        handle: (handler) => __TAURI_EVENT.listen(name, async (event) => {{
+            const {{ id, payload }} = /** @type {{{{ id: string, payload: unknown }}}} */ (event.payload);
+            try {{
+                const response = await handler(deserialize ? deserialize(payload) : /** @type {{TRequest}} */ (payload));
+                await respond({{ id, response: serialize ? serialize(response) : response }});
+            }} catch (e) {{
+                await respond({{ id, error: e instanceof Error ? e.message : String(e) }});
+            }}
+        }}, {{ target: __TAURI_WEBVIEW.getCurrentWebview().label }}),
+    }};
+}}"#
+        )
+    } else {
+        format!(
+            r#"function makeRequest<TRequest, TResponse>(name: string, deserialize?: (payload: any) => TRequest, serialize?: (response: TResponse) => unknown) {{
+    const respond = (response: {{ id: string, response?: unknown, error?: string }}) => {invoke}({command}, response);
+
+    return {{
+        // Only the requests sent to this webview are handled.
+        handle: (handler: (request: TRequest) => TResponse | Promise<TResponse>) => __TAURI_EVENT.listen<{{ id: string, payload: unknown }}>(name, async (event) => {{
+            const {{ id, payload }} = event.payload;
+            try {{
+                const response = await handler((deserialize ? deserialize(payload) : payload) as TRequest);
+                await respond({{ id, response: serialize ? serialize(response) : response }});
+            }} catch (e) {{
+                await respond({{ id, error: e instanceof Error ? e.message : String(e) }});
+            }}
+        }}, {{ target: __TAURI_WEBVIEW.getCurrentWebview().label }})
+    }};
+}}"#
+        )
+    }
+}
+
+fn is_reserved_name(cfg: &BuilderConfiguration, name: &str) -> bool {
+    RESERVED_NDT_NAMES.contains(&name)
+        || (!cfg.window_labels.is_empty() && name == "WindowLabel")
+        || (has_invoke_wrapper(cfg) && name == "__TAURI_INVOKE_RAW")
+        || (cfg.catch_panics && name == "CommandPanic")
+        || (cfg.has_middleware && name == "CommandRejected")
+        || (cfg.typed_argument_errors && name == "InvalidArguments")
+        || (cfg.error_handling == ErrorHandlingMode::Throw && name == "CommandError")
+        || (cfg.error_handling == ErrorHandlingMode::Effect && name == "Effect")
+        || (cfg.error_handling == ErrorHandlingMode::Neverthrow && name == "ResultAsync")
+}
+
+const RESERVED_NDT_NAMES: &[&str] = &[
     "Channel",
     "__TAURI_EVENT",
+    "__TAURI_WEBVIEW",
     "__TAURI_INVOKE",
     "typedError",
     "makeEvent",
+    "makeListenEvent",
+    "makeEmitEvent",
+    "makeStickyEvent",
+    "makeRequest",
     "mapChannel",
+    "channelStream",
 ];
 
 const FRAMEWORK_HEADER: &str =
     "// This file has been generated by Tauri Specta. Do not edit this file manually.";
 
+const COMMAND_ERROR_IMPL_TS: &str = r#"export class CommandError<TName extends string = string, E = unknown> extends Error {
+    readonly command: TName;
+    readonly error: E;
+    readonly cause: unknown;
+
+    constructor(command: TName, error: E, cause: unknown) {
+        super(`Command '${command}' failed`);
+        this.name = "CommandError";
+        this.command = command;
+        this.error = error;
+        this.cause = cause;
+    }
+}
+
+async function typedError<T, E, TName extends string>(command: TName, result: Promise<T>, mapError?: (error: any) => E): Promise<T> {
+    try {
+        return await result;
+    } catch (e) {
+        if (e instanceof Error) throw e;
+        throw new CommandError<TName, E>(command, mapError ? mapError(e) : e as E, e);
+    }
+}"#;
+
+const COMMAND_ERROR_IMPL_JS: &str = r#"/**
+ * @template {string} [TName=string]
+ * @template [E=unknown]
+ */
+export class CommandError extends Error {
+    /**
+     * @param {TName} command
+     * @param {E} error
+     * @param {unknown} cause
+     */
+    constructor(command, error, cause) {
+        super(`Command '${command}' failed`);
+        this.name = "CommandError";
+        /** @readonly */
+        this.command = command;
+        /** @readonly */
+        this.error = error;
+        /** @readonly */
+        this.cause = cause;
+    }
+}
+
+/**
+  * @template T
+  * @template E
+  * @template {string} TName
+  * @param {TName} command
+  * @param {Promise<T>} result
+  * @param {(error: any) => E} [mapError]
+  * @returns {Promise<T>}
+  */
+async function typedError(command, result, mapError) {
+    try {
+        return await result;
+    } catch (e) {
+        if (e instanceof Error) throw e;
+        throw new CommandError(command, mapError ? mapError(e) : e, e);
+    }
+}"#;
+
 const TYPED_ERROR_IMPL_TS: &str = r#"async function typedError<T, E>(result: Promise<T>): Promise<{ status: "ok"; data: T } | { status: "error"; error: E }> {
     try {
         return { status: "ok", data: await result };
@@ -1184,6 +1964,50 @@ const TYPED_ERROR_IMPL_TS: &str = r#"async function typedError<T, E>(result: Pro
     }
 }"#;
 
+const TYPED_ERROR_PANIC_IMPL_TS: &str = r#"async function typedError<T, E>(result: Promise<T>): Promise<{ status: "ok"; data: T } | { status: "error"; error: E } | { status: "panic"; message: string }> {
+    try {
+        return { status: "ok", data: await result };
+    } catch (e) {
+        if (e instanceof CommandPanic) return { status: "panic", message: e.message };
+        if (e instanceof Error) throw e;
+        return { status: "error", error: e as any };
+    }
+}"#;
+
+const COMMAND_PANIC_IMPL_TS: &str = r#"export class CommandPanic extends Error {
+    constructor(message: string) {
+        super(message);
+        this.name = "CommandPanic";
+    }
+}"#;
+
+const COMMAND_REJECTED_IMPL_TS: &str = r#"export class CommandRejected extends Error {
+    constructor(message: string) {
+        super(message);
+        this.name = "CommandRejected";
+    }
+}"#;
+
+const INVALID_ARGUMENTS_IMPL_TS: &str = r#"export class InvalidArguments {
+    readonly command: string;
+    readonly argument: string;
+    readonly path: (string | number)[];
+    readonly message: string;
+
+    constructor(command: string, argument: string, path: (string | number)[], message: string) {
+        this.command = command;
+        this.argument = argument;
+        this.path = path;
+        this.message = message;
+    }
+}
+
+function invalidArguments(e: unknown): unknown {
+    if (typeof e !== "object" || e === null || !("__tauriSpectaInvalidArguments" in e)) return e;
+    const { command, argument, path, message } = e.__tauriSpectaInvalidArguments as { command: string; argument: string; path: (string | number)[]; message: string };
+    return new InvalidArguments(command, argument, path, message);
+}"#;
+
 const DATA_ERROR_IMPL_TS: &str = r#"async function typedError<T, E>(result: Promise<T>): Promise<{ data: T; error: null } | { data: null; error: E }> {
     try {
         return { data: await result, error: null };
@@ -1207,6 +2031,98 @@ function mapChannel(channel, deserialize) {
     return new Channel((payload) => channel.onmessage(deserialize(payload)));
 }"#;
 
+const CHANNEL_STREAM_IMPL_TS: &str = r#"function channelStream<T, R>(run: (channel: Channel<T>) => PromiseLike<R>): AsyncIterable<T> & { result: Promise<R> } {
+    const buffer: T[] = [];
+    let settled = false;
+    let failure: { error: unknown } | undefined;
+    let wake: (() => void) | undefined;
+    const notify = () => {
+        wake?.();
+        wake = undefined;
+    };
+
+    const channel = new Channel<T>();
+    channel.onmessage = (message) => {
+        buffer.push(message);
+        notify();
+    };
+    // The stream ends once the command has settled and every message has been delivered.
+    const result = Promise.resolve(run(channel));
+    result.then(
+        () => {
+            settled = true;
+            notify();
+        },
+        (error) => {
+            failure = { error };
+            notify();
+        },
+    );
+
+    return {
+        result,
+        async *[Symbol.asyncIterator]() {
+            while (true) {
+                if (buffer.length > 0) yield buffer.shift() as T;
+                else if (failure) throw failure.error;
+                else if (settled) return;
+                else await new Promise<void>((resolve) => (wake = resolve));
+            }
+        },
+    };
+}"#;
+
+const CHANNEL_STREAM_IMPL_JS: &str = r#"/**
+ * @template T
+ * @template R
+ * @param {(channel: Channel<T>) => PromiseLike<R>} run
+ * @returns {AsyncIterable<T> & { result: Promise<R> }}
+ */
+function channelStream(run) {
+    /** @type {T[]} */
+    const buffer = [];
+    let settled = false;
+    /** @type {{ error: unknown } | undefined} */
+    let failure;
+    /** @type {(() => void) | undefined} */
+    let wake;
+    const notify = () => {
+        wake?.();
+        wake = undefined;
+    };
+
+    /** @type {Channel<T>} */
+    const channel = new Channel();
+    channel.onmessage = (message) => {
+        buffer.push(message);
+        notify();
+    };
+    // The stream ends once the command has settled and every message has been delivered.
+    const result = Promise.resolve(run(channel));
+    result.then(
+        () => {
+            settled = true;
+            notify();
+        },
+        (error) => {
+            failure = { error };
+            notify();
+        },
+    );
+
+    return {
+        result,
+        async *[Symbol.asyncIterator]() {
+            while (true) {
+                if (buffer.length > 0) yield /** @type {T} */ (buffer.shift());
+                else if (failure) throw failure.error;
+                else if (settled) return;
+                else await new Promise((resolve) => (wake = resolve));
+            }
+        },
+    };
+}"#;
+
 const TYPED_ERROR_IMPL_JS: &str = r#"/**
   * @template T
   * @template E
@@ -1222,6 +2138,67 @@ async function typedError(result) {
     }
 }"#;
 
+const TYPED_ERROR_PANIC_IMPL_JS: &str = r#"/**
+  * @template T
+  * @template E
+  * @param {Promise<T>} result
+  * @returns {Promise<{ status: "ok"; data: T } | { status: "error"; error: E } | { status: "panic"; message: string }>}
+  */
+async function typedError(result) {
+    try {
+        return { status: "ok", data: await result };
+    } catch (e) {
+        if (e instanceof CommandPanic) return { status: "panic", message: e.message };
+        if (e instanceof Error) throw e;
+        return { status: "error", error: e };
+    }
+}"#;
+
+const COMMAND_PANIC_IMPL_JS: &str = r#"export class CommandPanic extends Error {
+    /** @param {string} message */
+    constructor(message) {
+        super(message);
+        this.name = "CommandPanic";
+    }
+}"#;
+
+const COMMAND_REJECTED_IMPL_JS: &str = r#"export class CommandRejected extends Error {
+    /** @param {string} message */
+    constructor(message) {
+        super(message);
+        this.name = "CommandRejected";
+    }
+}"#;
+
+const INVALID_ARGUMENTS_IMPL_JS: &str = r#"export class InvalidArguments {
+    /**
+     * @param {string} command
+     * @param {string} argument
+     * @param {(string | number)[]} path
+     * @param {string} message
+     */
+    constructor(command, argument, path, message) {
+        /** @readonly */
+        this.command = command;
+        /** @readonly */
+        this.argument = argument;
+        /** @readonly */
+        this.path = path;
+        /** @readonly */
+        this.message = message;
+    }
+}
+
+/**
+ * @param {unknown} e
+ * @returns {unknown}
+ */
+function invalidArguments(e) {
+    if (typeof e !== "object" || e === null || !("__tauriSpectaInvalidArguments" in e)) return e;
+    const { command, argument, path, message } = /** @type {{ command: string; argument: string; path: (string | number)[]; message: string }} */ (e.__tauriSpectaInvalidArguments);
+    return new InvalidArguments(command, argument, path, message);
+}"#;
+
 const DATA_ERROR_IMPL_JS: &str = r#"/**
   * @template T
   * @template E
@@ -1237,18 +2214,52 @@ async function typedError(result) {
     }
 }"#;
 
+const EFFECT_IMPL_TS: &str = r#"function typedError<T, E>(result: () => Promise<T>): Effect.Effect<T, E> {
+    return Effect.tryPromise({ try: result, catch: (e) => e as E });
+}"#;
+
+const EFFECT_IMPL_JS: &str = r#"/**
+  * @template T
+  * @template E
+  * @param {() => Promise<T>} result
+  * @returns {Effect.Effect<T, E>}
+  */
+function typedError(result) {
+    return Effect.tryPromise({ try: result, catch: (e) => /** @type {E} */ (e) });
+}"#;
+
+const NEVERTHROW_IMPL_TS: &str = r#"function typedError<T, E>(result: Promise<T>): ResultAsync<T, E> {
+    return ResultAsync.fromPromise(result, (e) => e as E);
+}"#;
+
+const NEVERTHROW_IMPL_JS: &str = r#"/**
+  * @template T
+  * @template E
+  * @param {Promise<T>} result
+  * @returns {ResultAsync<T, E>}
+  */
+function typedError(result) {
+    return ResultAsync.fromPromise(result, (e) => /** @type {E} */ (e));
+}"#;
+
 const TYPED_ERROR_ASSERTION_TS: &str = "const _assertTypedErrorFollowsContract: <T, E>(result: Promise<T>) => Promise<any> = typedError;";
 
-const MAKE_EVENT_IMPL_TS: &str = r#"type EventEmit<T> = [T] extends [null] ? () => Promise<void> : (payload: T) => Promise<void>;
+const TYPED_ERROR_ASSERTION_EFFECT_TS: &str = "const _assertTypedErrorFollowsContract: <T, E>(result: () => Promise<T>) => Effect.Effect<T, E> = typedError;";
 
-function makeEvent<TListen, TEmit = TListen>(name: string, serialize?: (payload: TEmit) => unknown, deserialize?: (payload: any) => TListen) {
+const TYPED_ERROR_ASSERTION_NEVERTHROW_TS: &str = "const _assertTypedErrorFollowsContract: <T, E>(result: Promise<T>) => ResultAsync<T, E> = typedError;";
+
+const EVENT_EMIT_TYPES_TS: &str = r#"type EventEmit<T> = [T] extends [null] ? () => Promise<void> : (payload: T) => Promise<void>;
+type EventEmitTo<T, TLabel extends string> = [T] extends [null] ? (target: TLabel | __TAURI_EVENT.EventTarget) => Promise<void> : (target: TLabel | __TAURI_EVENT.EventTarget, payload: T) => Promise<void>;"#;
+
+const MAKE_EVENT_IMPL_TS: &str = r#"function makeEvent<TListen, TEmit = TListen, TLabel extends string = string>(name: string, serialize?: (payload: TEmit) => unknown, deserialize?: (payload: any) => TListen) {
     const mapEvent = (cb: __TAURI_EVENT.EventCallback<TListen>) => (event: __TAURI_EVENT.Event<any>) => cb({ ...event, payload: deserialize ? deserialize(event.payload) : event.payload });
     const mapPayload = (payload: TEmit) => serialize ? serialize(payload) : payload;
 
     const base = {
         listen: (cb: __TAURI_EVENT.EventCallback<TListen>) => __TAURI_EVENT.listen(name, mapEvent(cb)),
         once: (cb: __TAURI_EVENT.EventCallback<TListen>) => __TAURI_EVENT.once(name, mapEvent(cb)),
-        emit: ((payload: TEmit) => __TAURI_EVENT.emit(name, mapPayload(payload)) as unknown) as EventEmit<TEmit>
+        emit: ((payload: TEmit) => __TAURI_EVENT.emit(name, mapPayload(payload)) as unknown) as EventEmit<TEmit>,
+        emitTo: ((target: TLabel | __TAURI_EVENT.EventTarget, payload: TEmit) => __TAURI_EVENT.emitTo(target, name, mapPayload(payload)) as unknown) as EventEmitTo<TEmit, TLabel>
     };
 
     const fn = (target: import("@tauri-apps/api/webview").Webview | import("@tauri-apps/api/window").Window) => ({
@@ -1260,8 +2271,40 @@ function makeEvent<TListen, TEmit = TListen>(name: string, serialize?: (payload:
     return Object.assign(fn, base);
 }"#;
 
+const MAKE_LISTEN_EVENT_IMPL_TS: &str = r#"function makeListenEvent<T>(name: string, deserialize?: (payload: any) => T) {
+    const mapEvent = (cb: __TAURI_EVENT.EventCallback<T>) => (event: __TAURI_EVENT.Event<any>) => cb({ ...event, payload: deserialize ? deserialize(event.payload) : event.payload });
+
+    const base = {
+        listen: (cb: __TAURI_EVENT.EventCallback<T>) => __TAURI_EVENT.listen(name, mapEvent(cb)),
+        once: (cb: __TAURI_EVENT.EventCallback<T>) => __TAURI_EVENT.once(name, mapEvent(cb))
+    };
+
+    const fn = (target: import("@tauri-apps/api/webview").Webview | import("@tauri-apps/api/window").Window) => ({
+        listen: (cb: __TAURI_EVENT.EventCallback<T>) => target.listen(name, mapEvent(cb)),
+        once: (cb: __TAURI_EVENT.EventCallback<T>) => target.once(name, mapEvent(cb))
+    });
+
+    return Object.assign(fn, base);
+}"#;
+
+const MAKE_EMIT_EVENT_IMPL_TS: &str = r#"function makeEmitEvent<T, TLabel extends string = string>(name: string, serialize?: (payload: T) => unknown) {
+    const mapPayload = (payload: T) => serialize ? serialize(payload) : payload;
+
+    const base = {
+        emit: ((payload: T) => __TAURI_EVENT.emit(name, mapPayload(payload)) as unknown) as EventEmit<T>,
+        emitTo: ((target: TLabel | __TAURI_EVENT.EventTarget, payload: T) => __TAURI_EVENT.emitTo(target, name, mapPayload(payload)) as unknown) as EventEmitTo<T, TLabel>
+    };
+
+    const fn = (target: import("@tauri-apps/api/webview").Webview | import("@tauri-apps/api/window").Window) => ({
+        emit: ((payload: T) => target.emit(name, mapPayload(payload)) as unknown) as EventEmit<T>
+    });
+
+    return Object.assign(fn, base);
+}"#;
+
 const MAKE_EVENT_IMPL_JS: &str = r#"/**
  * @template T
+ * @template {string} [L=string]
  * @param {string} name
  * @param {(payload: T) => unknown} [serialize]
  * @param {(payload: any) => T} [deserialize]
@@ -1277,6 +2320,11 @@ function makeEvent(name, serialize, deserialize) {
         once: (cb) => __TAURI_EVENT.once(name, mapEvent(cb)),
         /** @param {T} payload */
         emit: (payload) => __TAURI_EVENT.emit(name, mapPayload(payload)),
+        /**
+         * @param {L | __TAURI_EVENT.EventTarget} target
+         * @param {T} payload
+         */
+        emitTo: (target, payload) => __TAURI_EVENT.emitTo(target, name, mapPayload(payload)),
     };
 
     /** @param {import("@tauri-apps/api/webview").Webview | import("@tauri-apps/api/window").Window} target */
@@ -1292,9 +2340,68 @@ function makeEvent(name, serialize, deserialize) {
     return Object.assign(fn, base);
 }"#;
 
+const MAKE_LISTEN_EVENT_IMPL_JS: &str = r#"/**
+ * @template T
+ * @param {string} name
+ * @param {(payload: any) => T} [deserialize]
+ */
+function makeListenEvent(name, deserialize) {
+    const mapEvent = (cb) => (event) => cb({ ...event, payload: deserialize ? deserialize(event.payload) : event.payload });
+
+    const base = {
+        /** @param {__TAURI_EVENT.EventCallback<T>} cb */
+        listen: (cb) => __TAURI_EVENT.listen(name, mapEvent(cb)),
+        /** @param {__TAURI_EVENT.EventCallback<T>} cb */
+        once: (cb) => __TAURI_EVENT.once(name, mapEvent(cb)),
+    };
+
+    /** @param {import("@tauri-apps/api/webview").Webview | import("@tauri-apps/api/window").Window} target */
+    const fn = (target) => ({
+        /** @param {__TAURI_EVENT.EventCallback<T>} cb */
+        listen: (cb) => target.listen(name, mapEvent(cb)),
+        /** @param {__TAURI_EVENT.EventCallback<T>} cb */
+        once: (cb) => target.once(name, mapEvent(cb)),
+    });
+
+    return Object.assign(fn, base);
+}"#;
+
+const MAKE_EMIT_EVENT_IMPL_JS: &str = r#"/**
+ * @template T
+ * @template {string} [L=string]
+ * @param {string} name
+ * @param {(payload: T) => unknown} [serialize]
+ */
+function makeEmitEvent(name, serialize) {
+    const mapPayload = (payload) => serialize ? serialize(payload) : payload;
+
+    const base = {
+        /** @param {T} payload */
+        emit: (payload) => __TAURI_EVENT.emit(name, mapPayload(payload)),
+        /**
+         * @param {L | __TAURI_EVENT.EventTarget} target
+         * @param {T} payload
+         */
+        emitTo: (target, payload) => __TAURI_EVENT.emitTo(target, name, mapPayload(payload)),
+    };
+
+    /** @param {import("@tauri-apps/api/webview").Webview | import("@tauri-apps/api/window").Window} target */
+    const fn = (target) => ({
+        /** @param {T} payload */
+        emit: (payload) => target.emit(name, mapPayload(payload)),
+    });
+
+    return Object.assign(fn, base);
+}"#;
+
 #[cfg(test)]
 mod tests {
-    use std::fs;
+    use std::{
+        any::TypeId,
+        fs,
+        path::Path,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
 
     use serde::{Deserialize, Serialize};
     use specta::{
@@ -1304,7 +2411,10 @@ mod tests {
     };
     use specta_typescript::{JSDoc, Layout, Typescript};
 
-    use crate::{Builder, ErrorHandlingMode, collect_commands};
+    use crate::{
+        Builder, BuilderConfiguration, Casing, ErrorHandlingMode, Event, EventDirection,
+        LanguageExt, Request, collect_commands, collect_events, collect_requests,
+    };
 
     #[tauri::command]
     #[specta]
@@ -1318,6 +2428,12 @@ mod tests {
         Ok(String::new())
     }
 
+    #[tauri::command]
+    #[specta]
+    fn app_version() -> String {
+        String::new()
+    }
+
     #[derive(Serialize, Type)]
     struct UnitError;
 
@@ -1362,6 +2478,82 @@ mod tests {
         Message(String),
     }
 
+    #[tauri::command]
+    #[specta]
+    fn stream_logs(level: u8, on_log: tauri::ipc::Channel<String>) -> Result<u32, String> {
+        on_log
+            .send(level.to_string())
+            .map_err(|err| err.to_string())?;
+        Ok(0)
+    }
+
+    #[derive(Clone, Serialize, Deserialize, Type)]
+    struct Ping(String);
+
+    impl Event for Ping {
+        const NAME: &'static str = "ping";
+    }
+
+    #[derive(Clone, Serialize, Deserialize, Type)]
+    struct Announcement(String);
+
+    impl Event for Announcement {
+        const NAME: &'static str = "announcement";
+        const DOCS: &'static str = "Shown in the status bar.";
+        const DEPRECATED: Option<&'static str> = Some("Use notifications instead");
+    }
+
+    /// Sent when the connection changes.
+    #[derive(Clone, Serialize, Deserialize, Type)]
+    struct Connection(bool);
+
+    impl Event for Connection {
+        const NAME: &'static str = "connection";
+        const DOCS: &'static str = "Overridden by the doc comments.";
+    }
+
+    #[derive(Clone, Serialize, Deserialize, Type)]
+    struct Tagged<T>(T);
+
+    impl<T: Type + 'static> Event for Tagged<T> {
+        const NAME: &'static str = "tagged";
+    }
+
+    #[derive(Clone, Serialize, Deserialize, Type)]
+    struct Progress(u8);
+
+    impl Event for Progress {
+        const NAME: &'static str = "progress";
+        const DIRECTION: EventDirection = EventDirection::ToFrontend;
+    }
+
+    #[derive(Clone, Serialize, Deserialize, Type)]
+    struct Submit(String);
+
+    impl Event for Submit {
+        const NAME: &'static str = "submit";
+        const DIRECTION: EventDirection = EventDirection::ToBackend;
+    }
+
+    #[derive(Clone, Serialize, Deserialize, Type)]
+    struct Status(String);
+
+    impl Event for Status {
+        const NAME: &'static str = "status";
+        const STICKY: bool = true;
+    }
+
+    #[derive(Serialize, Type)]
+    struct ConfirmDelete {
+        path: String,
+    }
+
+    impl Request for ConfirmDelete {
+        const NAME: &'static str = "confirm-delete";
+        const DOCS: &'static str = "Ask the user to confirm deleting a file.";
+        type Response = bool;
+    }
+
     #[tauri::command]
     #[specta]
     fn unit_struct_error() -> Result<String, UnitError> {
@@ -1392,6 +2584,32 @@ mod tests {
         Ok(String::new())
     }
 
+    /// Export TypeScript and JSDoc bindings with the default settings and return both.
+    fn export_ts_js(builder: Builder<tauri::Wry>) -> (String, String) {
+        static EXPORTS: AtomicUsize = AtomicUsize::new(0);
+        let output_dir = std::env::temp_dir().join(format!(
+            "tauri-specta-test-{}-{}",
+            std::process::id(),
+            EXPORTS.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&output_dir).expect("failed to create test output directory");
+
+        let ts_path = output_dir.join("bindings.ts");
+        builder
+            .clone()
+            .export(Typescript::default(), &ts_path)
+            .expect("failed to export TypeScript bindings");
+        let js_path = output_dir.join("bindings.js");
+        builder
+            .export(JSDoc::default(), &js_path)
+            .expect("failed to export JSDoc bindings");
+        let ts = fs::read_to_string(ts_path).expect("failed to read TypeScript bindings");
+        let js = fs::read_to_string(js_path).expect("failed to read JSDoc bindings");
+
+        fs::remove_dir_all(output_dir).expect("failed to remove test output directory");
+        (ts, js)
+    }
+
     #[test]
     fn reserved_runtime_names_use_exported_type_names() {
         let output_dir = std::path::Path::new("target/tests/reserved-runtime-names");
@@ -1483,40 +2701,22 @@ mod tests {
 
     #[test]
     fn data_error_mode_exports_discriminated_results() {
-        let output_dir = std::env::temp_dir().join(format!(
-            "tauri-specta-data-error-test-{}",
-            std::process::id()
-        ));
-        fs::create_dir_all(&output_dir).expect("failed to create test output directory");
-
         let builder = Builder::<tauri::Wry>::new()
             .commands(collect_commands![nullable_result])
             .error_handling(ErrorHandlingMode::DataError);
 
-        let ts_path = output_dir.join("bindings.ts");
-        builder
-            .clone()
-            .export(Typescript::default(), &ts_path)
-            .expect("failed to export TypeScript bindings");
-        let ts = fs::read_to_string(ts_path).expect("failed to read TypeScript bindings");
+        let (ts, js) = export_ts_js(builder);
         assert!(ts.contains("typedError<string | null, string>"));
         assert!(ts.contains("Promise<{ data: T; error: null } | { data: null; error: E }>"));
         assert!(ts.contains("return { data: await result, error: null };"));
         assert!(ts.contains("return { data: null, error: e as any };"));
 
-        let js_path = output_dir.join("bindings.js");
-        builder
-            .export(JSDoc::default(), &js_path)
-            .expect("failed to export JSDoc bindings");
-        let js = fs::read_to_string(js_path).expect("failed to read JSDoc bindings");
         assert!(js.contains(
             "@returns {Promise<{ data: string | null; error: null } | { data: null; error: string }>}"
         ));
         assert!(
             js.contains("@returns {Promise<{ data: T; error: null } | { data: null; error: E }>}")
         );
-
-        fs::remove_dir_all(output_dir).expect("failed to remove test output directory");
     }
 
     #[test]
@@ -1583,4 +2783,324 @@ mod tests {
             "DataError mode requires a non-nullable command error type because null marks a successful result"
         ));
     }
+
+    #[test]
+    fn window_labels_type_emit_to() {
+        let builder = Builder::<tauri::Wry>::new()
+            .events(collect_events![Ping])
+            .window_labels(["main", "settings", "main"]);
+
+        let (ts, js) = export_ts_js(builder);
+        assert!(ts.contains("export type WindowLabel = \"main\" | \"settings\";"));
+        assert!(ts.contains("makeEvent<Ping, Ping, WindowLabel>(\"ping\")"));
+        assert!(ts.contains("__TAURI_EVENT.emitTo(target, name, mapPayload(payload))"));
+
+        assert!(js.contains("@typedef {\"main\" | \"settings\"} WindowLabel"));
+        assert!(js.contains("@type {ReturnType<typeof makeEvent<Ping, WindowLabel>>}"));
+
+        let (ts, _) = export_ts_js(Builder::<tauri::Wry>::new().events(collect_events![Ping]));
+        assert!(!ts.contains("WindowLabel"));
+        assert!(ts.contains("makeEvent<Ping, Ping>(\"ping\")"));
+    }
+
+    #[test]
+    fn event_docs_are_rendered_on_events() {
+        let builder = Builder::<tauri::Wry>::new()
+            .plugin_name("example")
+            .events(collect_events![Announcement, Connection]);
+
+        let (ts, js) = export_ts_js(builder);
+        assert!(ts.contains("Shown in the status bar."));
+        // The doc comments of the type take precedence over `Event::DOCS`.
+        assert!(ts.contains("Sent when the connection changes."));
+        assert!(!ts.contains("Overridden by the doc comments."));
+        assert!(ts.contains("Tauri event: `plugin:example:announcement`"));
+        assert!(ts.contains("@deprecated Use notifications instead"));
+
+        assert!(js.contains("Tauri event: `plugin:example:announcement`"));
+        assert!(js.contains("@deprecated Use notifications instead"));
+        assert!(js.contains("@type {ReturnType<typeof makeEvent<Announcement>>}"));
+    }
+
+    #[test]
+    fn generic_event_instantiations_can_be_renamed() {
+        let (ts, _) = export_ts_js(Builder::<tauri::Wry>::new().events(collect_events![
+            Tagged::<String> as "tagged-string",
+            Tagged::<u32> as "tagged-number",
+        ]));
+        assert!(ts.contains("taggedString: makeEvent<"));
+        assert!(ts.contains("(\"tagged-string\")"));
+        assert!(ts.contains("taggedNumber: makeEvent<"));
+        assert!(ts.contains("(\"tagged-number\")"));
+    }
+
+    #[test]
+    fn builder_configuration_keeps_event_references() {
+        struct Inspect;
+
+        impl LanguageExt for Inspect {
+            type Error = std::io::Error;
+
+            fn export(self, cfg: &BuilderConfiguration, _: &Path) -> Result<(), Self::Error> {
+                assert_eq!(cfg.events.keys().collect::<Vec<_>>(), ["ping"]);
+                assert_eq!(cfg.events["ping"].0, TypeId::of::<Ping>());
+                assert!(cfg.event_definitions["ping"].reference().is_some());
+                Ok(())
+            }
+        }
+
+        Builder::<tauri::Wry>::new()
+            .events(collect_events![Ping])
+            .export(Inspect, "")
+            .expect("failed to inspect configuration");
+    }
+
+    #[test]
+    fn event_direction_limits_generated_methods() {
+        let builder = Builder::<tauri::Wry>::new()
+            .events(collect_events![Progress, Submit])
+            .window_labels(["main"]);
+
+        let (ts, js) = export_ts_js(builder);
+        assert!(ts.contains("makeListenEvent<Progress>(\"progress\")"));
+        assert!(ts.contains("makeEmitEvent<Submit, WindowLabel>(\"submit\")"));
+        assert!(!ts.contains("function makeEvent"));
+
+        assert!(js.contains("makeListenEvent(\"progress\")"));
+        assert!(js.contains("makeEmitEvent(\"submit\")"));
+        assert!(!js.contains("function makeEvent("));
+    }
+
+    #[test]
+    fn sticky_events_expose_current_value() {
+        let builder = Builder::<tauri::Wry>::new()
+            .plugin_name("example")
+            .events(collect_events![Ping, Status]);
+
+        let (ts, js) = export_ts_js(builder);
+        // Sticky events need `invoke` even without any commands.
+        assert!(ts.contains("import { invoke as __TAURI_INVOKE } from \"@tauri-apps/api/core\";"));
+        assert!(ts.contains(
+            "makeStickyEvent(\"plugin:example:status\", makeEvent<Status, Status>(\"plugin:example:status\"))"
+        ));
+        assert!(ts.contains("makeEvent<Ping, Ping>(\"plugin:example:ping\")"));
+        assert!(ts.contains("\"plugin:example|__tauri_specta_sticky\""));
+
+        assert!(js.contains(
+            "@type {ReturnType<typeof makeStickyEvent<ReturnType<typeof makeEvent<Status>>>>}"
+        ));
+        assert!(js.contains("function makeStickyEvent(name, event, deserialize)"));
+    }
+
+    #[test]
+    fn requests_export_handlers() {
+        let (ts, js) =
+            export_ts_js(Builder::<tauri::Wry>::new().requests(collect_requests![ConfirmDelete]));
+        assert!(ts.contains("import * as __TAURI_EVENT from \"@tauri-apps/api/event\";"));
+        assert!(ts.contains("export const requests = {"));
+        assert!(
+            ts.contains("confirmDelete: makeRequest<ConfirmDelete, boolean>(\"confirm-delete\")")
+        );
+        assert!(ts.contains("Ask the user to confirm deleting a file."));
+        assert!(ts.contains("function makeRequest<TRequest, TResponse>("));
+        // Requests are only handled by the webview they're sent to, which responds through the hidden command.
+        assert!(ts.contains("import { invoke as __TAURI_INVOKE } from \"@tauri-apps/api/core\";"));
+        assert!(ts.contains("import * as __TAURI_WEBVIEW from \"@tauri-apps/api/webview\";"));
+        assert!(ts.contains("{ target: __TAURI_WEBVIEW.getCurrentWebview().label }"));
+        assert!(ts.contains("__TAURI_INVOKE(\"__tauri_specta_respond\", response)"));
+
+        assert!(js.contains("confirmDelete: makeRequest(\"confirm-delete\")"));
+        assert!(js.contains("@type {ReturnType<typeof makeRequest<ConfirmDelete, boolean>>}"));
+        assert!(js.contains("__TAURI_INVOKE(\"__tauri_specta_respond\", response)"));
+    }
+
+    #[test]
+    fn channel_commands_export_stream_helper() {
+        let builder = Builder::<tauri::Wry>::new().commands(collect_commands![stream_logs]);
+
+        let (ts, js) = export_ts_js(builder);
+        assert!(
+            ts.contains("stream: (level: number) => channelStream((onLog: Channel<string>) =>")
+        );
+        assert!(ts.contains("function channelStream<T, R>(run: (channel: Channel<T>) => PromiseLike<R>): AsyncIterable<T> & { result: Promise<R> }"));
+        // The stream ends once the command settles and surfaces the command's failure.
+        assert!(!ts.contains("cleanupCallback"));
+        assert!(ts.contains("else if (settled) return;"));
+        assert!(ts.contains("else if (failure) throw failure.error;"));
+        assert!(!ts.contains("setTimeout"));
+
+        assert!(js.contains("stream: (level) => channelStream((onLog) =>"));
+        assert!(js.contains("@returns {AsyncIterable<T> & { result: Promise<R> }}"));
+    }
+
+    #[test]
+    fn effect_and_neverthrow_modes_export_result_types() {
+        for (mode, import, invoke, infallible_invoke, returns, infallible_returns) in [
+            (
+                ErrorHandlingMode::Effect,
+                "import { Effect } from \"effect\";",
+                "typedError<string | null, string>(() => __TAURI_INVOKE(\"nullable_result\"))",
+                "Effect.promise(() => __TAURI_INVOKE<string>(\"app_version\"))",
+                "@returns {Effect.Effect<string | null, string>}",
+                "@returns {Effect.Effect<string, never>}",
+            ),
+            (
+                ErrorHandlingMode::Neverthrow,
+                "import { ResultAsync } from \"neverthrow\";",
+                "typedError<string | null, string>(__TAURI_INVOKE(\"nullable_result\"))",
+                "ResultAsync.fromSafePromise(__TAURI_INVOKE<string>(\"app_version\"))",
+                "@returns {ResultAsync<string | null, string>}",
+                "@returns {ResultAsync<string, never>}",
+            ),
+        ] {
+            let builder = Builder::<tauri::Wry>::new()
+                .commands(collect_commands![nullable_result, app_version])
+                .error_handling(mode);
+
+            let (ts, js) = export_ts_js(builder);
+            assert!(ts.contains(import));
+            assert!(ts.contains(invoke));
+            assert!(ts.contains(infallible_invoke));
+            // The rejection of the invoke is the typed error, and the error mapper never throws.
+            assert!(ts.contains("(e) => e as E"));
+            assert!(!ts.contains("status: \"ok\""));
+
+            assert!(js.contains(import));
+            assert!(js.contains(returns));
+            assert!(js.contains(infallible_returns));
+        }
+    }
+
+    #[test]
+    fn throw_mode_wraps_errors_in_command_error() {
+        let builder = Builder::<tauri::Wry>::new()
+            .commands(collect_commands![nullable_result])
+            .error_handling(ErrorHandlingMode::Throw);
+
+        let (ts, js) = export_ts_js(builder);
+        assert!(ts.contains(
+            "typedError<string | null, string, \"nullable_result\">(\"nullable_result\", __TAURI_INVOKE(\"nullable_result\"))"
+        ));
+        assert!(ts.contains(
+            "export class CommandError<TName extends string = string, E = unknown> extends Error"
+        ));
+        assert!(!ts.contains("_assertTypedErrorFollowsContract"));
+
+        assert!(js.contains("@throws {CommandError<\"nullable_result\", string>}"));
+        assert!(js.contains("@returns {Promise<string | null>}"));
+        assert!(js.contains("export class CommandError extends Error"));
+    }
+
+    #[test]
+    fn throw_mode_rejects_custom_typed_error_impl() {
+        let output_path = std::env::temp_dir().join(format!(
+            "tauri-specta-throw-typed-error-impl-test-{}.ts",
+            std::process::id()
+        ));
+        let err = Builder::<tauri::Wry>::new()
+            .commands(collect_commands![nullable_result])
+            .error_handling(ErrorHandlingMode::Throw)
+            .typed_error_impl("function typedError() {}")
+            .export(Typescript::default(), output_path)
+            .expect_err("a custom typedError implementation should fail to export in Throw mode");
+        assert!(err.to_string().contains(
+            "A custom `typedError` implementation can't be used in `ErrorHandlingMode::Throw`"
+        ));
+    }
+
+    #[test]
+    fn catch_panics_adds_panic_variant() {
+        let builder = Builder::<tauri::Wry>::new()
+            .commands(collect_commands![nullable_result])
+            .catch_panics();
+
+        let (ts, js) = export_ts_js(builder);
+        assert!(
+            ts.contains("import { invoke as __TAURI_INVOKE_RAW } from \"@tauri-apps/api/core\";")
+        );
+        assert!(ts.contains(
+            "function __TAURI_INVOKE<T>(...args: Parameters<typeof __TAURI_INVOKE_RAW>): Promise<T>"
+        ));
+        assert!(ts.contains("| { status: \"panic\"; message: string }"));
+
+        assert!(js.contains(
+            "@returns {Promise<{ status: \"ok\"; data: string | null } | { status: \"error\"; error: string } | { status: \"panic\"; message: string }>}"
+        ));
+        assert!(js.contains("export class CommandPanic extends Error"));
+    }
+
+    #[test]
+    fn middleware_rejections_are_thrown() {
+        let builder = Builder::<tauri::Wry>::new()
+            .commands(collect_commands![nullable_result])
+            .middleware(|ctx, next| next.run(ctx));
+
+        let (ts, js) = export_ts_js(builder);
+        assert!(ts.contains("export class CommandRejected extends Error"));
+        assert!(ts.contains("throw new CommandRejected(String(e.__tauriSpectaRejected));"));
+
+        assert!(js.contains("export class CommandRejected extends Error"));
+    }
+
+    #[test]
+    fn typed_argument_errors_extend_error_types() {
+        let builder = Builder::<tauri::Wry>::new()
+            .commands(collect_commands![nullable_result])
+            .typed_argument_errors();
+
+        let (ts, js) = export_ts_js(builder);
+        assert!(
+            ts.contains("import { invoke as __TAURI_INVOKE_RAW } from \"@tauri-apps/api/core\";")
+        );
+        assert!(ts.contains("export class InvalidArguments {"));
+        assert!(ts.contains(
+            "typedError<string | null, string | InvalidArguments>(__TAURI_INVOKE(\"nullable_result\"))"
+        ));
+        assert!(ts.contains("throw invalidArguments(e);"));
+        assert!(ts.contains("readonly path: (string | number)[];"));
+        // The error is recognized by its marker, not by parsing Tauri's message.
+        assert!(ts.contains("\"__tauriSpectaInvalidArguments\" in e"));
+        assert!(!ts.contains("invalid args"));
+
+        assert!(js.contains(
+            "@returns {Promise<{ status: \"ok\"; data: string | null } | { status: \"error\"; error: string | InvalidArguments }>}"
+        ));
+    }
+
+    #[test]
+    fn annotate_permissions_exports_required_permissions() {
+        let (ts, _) = export_ts_js(
+            Builder::<tauri::Wry>::new()
+                .plugin_name("my-plugin")
+                .commands(collect_commands![nullable_result])
+                .annotate_permissions(),
+        );
+        assert!(ts.contains("Requires the `my-plugin:allow-nullable-result` permission."));
+        assert!(ts.contains(
+            "export const permissions = {\n\tnullableResult: \"my-plugin:allow-nullable-result\",\n} as const;"
+        ));
+
+        // Keys which aren't valid identifiers are quoted.
+        let (kebab, _) = export_ts_js(
+            Builder::<tauri::Wry>::new()
+                .plugin_name("my-plugin")
+                .commands(collect_commands![nullable_result])
+                .function_casing(Casing::KebabCase)
+                .annotate_permissions(),
+        );
+        assert!(kebab.contains("\"nullable-result\": \"my-plugin:allow-nullable-result\","));
+        assert!(!kebab.contains("\tnullable-result:"));
+
+        // App commands aren't gated by permission identifiers.
+        let output_path = std::env::temp_dir().join(format!(
+            "tauri-specta-app-permissions-test-{}.ts",
+            std::process::id()
+        ));
+        let err = Builder::<tauri::Wry>::new()
+            .commands(collect_commands![nullable_result])
+            .annotate_permissions()
+            .export(Typescript::default(), output_path)
+            .expect_err("annotating app commands should fail to export");
+        assert!(err.to_string().contains("only be annotated for plugins"));
+    }
 }
@@ -24,6 +24,10 @@
 //! - `derive` - Enables the `Event` derive macro. This is only required if your using events.
 //! - `javascript` - Enables the JSDoc exporter.
 //! - `typescript` - Enables the Typescript exporter.
+//! - `tracing` - Enables [`tracing`](https://docs.rs/tracing) spans for command invocations and events for emitted and received events.
+//!   The span is entered while the command is dispatched, so with Tauri's `tracing` feature (which this enables) the `ipc::request::run` span instrumenting the command,
+//!   including the body of async commands and any messages sent through a [`Channel`](tauri::ipc::Channel), is recorded within it.
+//!   The span's `outcome` field is set to `handled`, `unhandled` if the command doesn't exist, or `panicked` if a panic was caught by [`Builder::catch_panics`].
 //!
 //! ## Setup
 //!
@@ -93,6 +97,21 @@
 //! console.log(await commands.greet("Brendan"));
 //! ```
 //!
+//! ## Channels
+//!
+//! Commands which take a single [`tauri::ipc::Channel`] argument also get a `stream` helper.
+//! It creates the channel for you and returns an [async iterable](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Iteration_protocols#the_async_iterator_and_async_iterable_protocols) of the channel's messages along with the command's result.
+//!
+//! ```ts
+//! const logs = commands.streamLogs.stream("debug");
+//!
+//! // Messages are buffered until they are consumed. The loop ends once the Rust side drops the
+//! // channel and every message has been delivered, or throws if the command itself fails.
+//! for await (const log of logs) console.log(log);
+//!
+//! console.log(await logs.result);
+//! ```
+//!
 //! ## Custom types
 //!
 //! Similar to [`serde::Serialize`] you must put the [`specta::Type`] derive macro on your own types to allow Specta to understand your types. For example:
@@ -161,10 +180,56 @@
 //!
 //! // Emit to a window
 //! await events.demoEvent(appWindow).emit("Test")
+//!
+//! // Emit to a window by its label
+//! await events.demoEvent.emitTo("settings", "Test")
 //! ```
 //!
 //! Refer to [`Event`] for all the possible methods for listening and emitting events.
 //!
+//! Register your window labels using [`Builder::window_labels`] to ensure `emitTo` only accepts known labels.
+//! The [`window_labels!`] macro can be used to define a matching Rust enum for [`Event::emit_to`].
+//!
+//! If an event should only be sent one way, set its [`EventDirection`] using `#[tauri_specta(direction = "to_frontend")]` or `#[tauri_specta(direction = "to_backend")]`.
+//! The frontend can then only listen to (or emit) the event, and the other methods fail to compile in Rust.
+//!
+//! Events which represent state can be made [sticky](Event::STICKY) using `#[tauri_specta(sticky)]`.
+//! The last payload emitted by the backend is remembered, and windows opened later can read it using `events.demoEvent.current()`
+//! or `events.demoEvent.listenWithCurrent(cb)`.
+//!
+//! Events emitted from tight loops, like progress updates, can be throttled using [`Event::emit_throttled`] or `#[tauri_specta(throttle_ms = 50)]`.
+//! Bursts are coalesced so only the latest payload is delivered once the interval has passed.
+//!
+//! Use [`EventGroup`] to define a related set of events as the variants of a single enum.
+//!
+//! ## Requests
+//!
+//! When the backend needs an answer from the frontend, like a confirmation rendered in your UI, define a [`Request`] and register it using [`Builder::requests`].
+//!
+//! ```rust
+//! use serde::{Serialize, Deserialize};
+//! use specta::Type;
+//! use tauri_specta::{Builder, Request, collect_requests};
+//!
+//! #[derive(Serialize, Deserialize, Type, Request)]
+//! #[tauri_specta(response = bool)]
+//! pub struct ConfirmDelete {
+//!     path: String,
+//! }
+//!
+//! let builder = Builder::<tauri::Wry>::new().requests(collect_requests![ConfirmDelete]);
+//! ```
+//!
+//! The frontend handles it using the generated bindings:
+//!
+//! ```ts
+//! import { requests } from "./bindings";
+//!
+//! await requests.confirmDelete.handle(async ({ path }) => confirm(`Delete ${path}?`));
+//! ```
+//!
+//! and the backend asks for the response using [`Request::ask`], e.g. `ConfirmDelete { path }.ask(&window, timeout).await`.
+//!
 //! ## Phase-specific types
 //!
 //! By default, Tauri Specta exports types using Serde-aware serialize and deserialize phases. When a Rust type has different Serde shapes for serialization and deserialization, Tauri Specta emits separate TypeScript aliases for those phases:
@@ -250,19 +315,31 @@
     html_favicon_url = "https://github.com/specta-rs/specta/raw/main/.github/logo-128.png"
 )]
 
+mod arguments;
 mod builder;
 mod casing;
 mod commands;
 mod event;
 mod lang;
 mod macros;
+mod middleware;
 mod name;
+mod panic;
+mod permissions;
+mod request;
+mod schema;
 
 pub use builder::{Builder, BuilderConfiguration, ErrorHandlingMode};
 pub use casing::Casing;
 pub use commands::Commands;
-pub use event::{Event, Events, TypedEvent};
+pub use event::{
+    Event, EventDefinition, EventDirection, EventGroup, Events, STICKY_COMMAND, TypedEvent,
+};
 pub use lang::LanguageExt;
+pub use middleware::{InvokeContext, Next, Rejection};
+pub use permissions::Permissions;
+pub use request::{RESPOND_COMMAND, Request, RequestDefinition, RequestError, Requests, Responder};
+pub use schema::SCHEMA_COMMAND;
 
 /// Implements the [`Event`](trait@crate::Event) trait for a struct.
 ///
@@ -272,6 +349,23 @@ pub use lang::LanguageExt;
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use tauri_specta_macros::Event;
 
+/// Implements the [`EventGroup`](trait@crate::EventGroup) trait for an enum.
+///
+/// Refer to the [`EventGroup`](trait@crate::EventGroup) trait for more information.
+///
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use tauri_specta_macros::EventGroup;
+
+/// Implements the [`Request`](trait@crate::Request) trait for a struct or enum.
+///
+/// The type of the response must be set using `#[tauri_specta(response = MyResponse)]`.
+/// Refer to the [`Request`](trait@crate::Request) trait for more information.
+///
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use tauri_specta_macros::Request;
+
 pub(crate) use event::EventRegistry;
 
 #[doc(hidden)]
@@ -280,15 +374,19 @@ pub mod internal {
     //! Nothing in this module has to conform to semver so it should not be used outside of this crate.
     //! It has to be public so the macro's can access it.
 
-    use std::{any::TypeId, sync::Arc};
+    use std::{any::TypeId, borrow::Cow, sync::Arc};
 
+    use serde::{Serialize, de::DeserializeOwned};
     use specta::{
-        Types,
-        datatype::{self, DataType},
+        Type, Types,
+        datatype::{self, DataType, NamedDataType, Reference},
     };
     use tauri::{Runtime, ipc::Invoke};
 
     use super::*;
+    use crate::event::deprecated_type;
+
+    pub use serde_json;
 
     /// called by `collect_commands` to construct `Commands`
     pub fn command<R: Runtime, F, T>(f: F, types: T) -> Commands<R>
@@ -299,22 +397,138 @@ pub mod internal {
         Commands(Arc::new(f), Arc::new(types))
     }
 
+    /// Implemented for everything `collect_events` accepts.
+    pub trait CollectEvents {
+        /// The name the event is registered with if it's not renamed.
+        const NAME: &'static str;
+
+        /// Register the event(s) to an `Events` under the given name.
+        fn register(events: &mut Events, name: &'static str);
+    }
+
+    impl<E: Event> CollectEvents for E {
+        const NAME: &'static str = E::NAME;
+
+        fn register(events: &mut Events, name: &'static str) {
+            register_event::<E>(events, name);
+        }
+    }
+
     /// called by `collect_events` to register events to an `Events`
     #[allow(clippy::panic)]
-    pub fn register_event<E: Event>(Events(events): &mut Events) {
+    pub fn register_event<E: Event>(Events(events): &mut Events, name: &'static str) {
         if events
-            .insert(E::NAME, |types| {
-                (
-                    TypeId::of::<E>(),
-                    match E::definition(types) {
-                        DataType::Reference(r) => r,
+            .insert(
+                Cow::Borrowed(name),
+                Arc::new(|types: &mut Types| {
+                    let data_type = match E::definition(types) {
+                        dt @ DataType::Reference(_) => dt,
                         _ => panic!("Can't register event {} with non-reference type", E::NAME),
-                    },
-                )
-            })
+                    };
+                    let ndt = named_type(&data_type, types);
+                    EventDefinition {
+                        type_id: TypeId::of::<E>(),
+                        direction: E::DIRECTION,
+                        group: None,
+                        docs: type_docs(ndt, E::DOCS),
+                        deprecated: ndt
+                            .and_then(|ndt| ndt.deprecated.clone())
+                            .or_else(|| deprecated_type(E::DEPRECATED)),
+                        sticky: E::STICKY,
+                        data_type,
+                    }
+                }),
+            )
+            .is_some()
+        {
+            panic!("Another event with name {name} is already registered!")
+        }
+    }
+
+    /// called by the `EventGroup` derive macro to register the event of a variant to an `Events`
+    #[allow(clippy::panic)]
+    pub fn register_group_event<G: EventGroup, P: Type>(
+        Events(events): &mut Events,
+        group: &'static str,
+        event: &'static str,
+        docs: &'static str,
+        deprecated: Option<&'static str>,
+    ) {
+        let name = format!("{group}:{event}");
+        if events
+            .insert(
+                Cow::Owned(name.clone()),
+                Arc::new(move |types: &mut Types| EventDefinition {
+                    type_id: TypeId::of::<G>(),
+                    data_type: P::definition(types),
+                    direction: G::DIRECTION,
+                    group: Some(group),
+                    docs: Cow::Borrowed(docs),
+                    deprecated: deprecated_type(deprecated),
+                    sticky: false,
+                }),
+            )
             .is_some()
         {
-            panic!("Another event with name {} is already registered!", E::NAME)
+            panic!("Another event with name {name} is already registered!")
         }
     }
+
+    /// called by `collect_requests` to register requests to a `Requests`
+    #[allow(clippy::panic)]
+    pub fn register_request<Q: Request>(Requests(requests): &mut Requests) {
+        if requests
+            .insert(
+                Q::NAME,
+                Arc::new(|types: &mut Types| {
+                    let request = Q::definition(types);
+                    RequestDefinition {
+                        type_id: TypeId::of::<Q>(),
+                        response: <Q::Response as Type>::definition(types),
+                        docs: type_docs(named_type(&request, types), Q::DOCS),
+                        request,
+                    }
+                }),
+            )
+            .is_some()
+        {
+            panic!(
+                "Another request with name {} is already registered!",
+                Q::NAME
+            )
+        }
+    }
+
+    /// The named type the data type refers to, which holds the doc comments and `#[deprecated]` attribute captured by `specta::Type`.
+    fn named_type<'a>(dt: &DataType, types: &'a Types) -> Option<&'a NamedDataType> {
+        match dt {
+            DataType::Reference(Reference::Named(r)) => types.get(r),
+            _ => None,
+        }
+    }
+
+    /// The doc comments of the named type, falling back to the docs set on the trait.
+    fn type_docs(ndt: Option<&NamedDataType>, fallback: &'static str) -> Cow<'static, str> {
+        match ndt {
+            Some(ndt) if !ndt.docs.is_empty() => ndt.docs.clone(),
+            _ => Cow::Borrowed(fallback),
+        }
+    }
+
+    /// called by the `EventGroup` derive macro to serialize the payload of a variant
+    pub fn group_payload<P: Serialize>(payload: &P) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(payload)
+    }
+
+    /// called by the `EventGroup` derive macro to deserialize the payload of a variant
+    pub fn group_from_payload<P: DeserializeOwned>(payload: &str) -> serde_json::Result<P> {
+        serde_json::from_str(payload)
+    }
+
+    /// called by the `EventGroup` derive macro when an event is not part of the group
+    pub fn unknown_group_event<T>(group: &str, event: &str) -> serde_json::Result<T> {
+        Err(serde::de::Error::custom(format!(
+            "Event {event} is not part of the group {group}"
+        )))
+    }
 }
@@ -19,6 +19,11 @@
 ///     format!("You've been greeted from a generic Rust function!")
 /// }
 ///
+/// #[cfg(debug_assertions)]
+/// #[tauri::command]
+/// #[specta::specta]
+/// fn debug_only() {}
+///
 /// mod hello {
 ///     #[tauri::command]
 ///     #[specta::specta] // < You must annotate your commands
@@ -34,17 +39,28 @@
 ///         // You can also pass a module.
 ///         hello::world,
 ///         // Unlike `tauri::generate_handler` you may need to specify generics.
-///         generic_command::<tauri::Wry>
+///         generic_command::<tauri::Wry>,
+///         // You can use `#[cfg(...)]` to conditionally include a command.
+///         #[cfg(debug_assertions)]
+///         debug_only,
 ///     ]);
 /// ```
 ///
 #[macro_export]
 macro_rules! collect_commands {
-    ($($b:ident $(:: $($p:ident)? $(<$($g:path),*>)? )* ),* $(,)?) => {
+    ($($(#[$meta:meta])* $b:ident $(:: $($p:ident)? $(<$($g:path),*>)? )* ),* $(,)?) => {
         // We strip generics (::<...>) from being parsed to Tauri as it doesn't support them.
         $crate::internal::command(
-            ::tauri::generate_handler![$($b $($(::$p)? )* ),*],
-            ::specta::function::collect_functions![$($b $($(::$p)? $(::<$($g),*>)? )* ),*],
+            ::tauri::generate_handler![$($(#[$meta])* $b $($(::$p)? )* ),*],
+            |types: &mut ::specta::Types| {
+                #[allow(unused_mut)]
+                let mut functions = ::std::vec::Vec::new();
+                $(
+                    $(#[$meta])*
+                    functions.extend((::specta::function::collect_functions![$b $($(::$p)? $(::<$($g),*>)? )*])(types));
+                )*
+                functions
+            },
         )
     };
 }
@@ -57,7 +73,7 @@ macro_rules! collect_commands {
 /// ```rust
 /// use serde::{Serialize, Deserialize};
 /// use specta::Type;
-/// use tauri_specta::{Event, Builder, collect_events};
+/// use tauri_specta::{Event, EventGroup, Builder, collect_events};
 ///
 /// #[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
 /// pub struct MyEvent(String);
@@ -65,6 +81,12 @@ macro_rules! collect_commands {
 /// #[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
 /// pub struct MyGenericEvent<T: Type + 'static>(T);
 ///
+/// #[derive(Debug, EventGroup)]
+/// pub enum Download {
+///     Started(u32),
+///     Finished,
+/// }
+///
 /// mod hello {
 /// # use serde::{Serialize, Deserialize};
 /// # use specta::Type;
@@ -82,15 +104,132 @@ macro_rules! collect_commands {
 ///         // You can also pass a module.
 ///         hello::World,
 ///         // or you can specify generics.
-///         MyGenericEvent::<String>
+///         MyGenericEvent::<String>,
+///         // Use `as` to give each instantiation of a generic event a distinct name.
+///         MyGenericEvent::<u32> as "my-generic-event-u32",
+///         // An event group registers an event for each of its variants.
+///         Download,
 ///     ]);
 /// ```
 ///
 #[macro_export]
 macro_rules! collect_events {
-    ($($event:path),* $(,)?) => {{
+    (@name $event:path) => {
+        <$event as $crate::internal::CollectEvents>::NAME
+    };
+    (@name $event:path as $name:literal) => {
+        $name
+    };
+    ($($event:path $(as $name:literal)?),* $(,)?) => {{
         let mut events: $crate::Events = ::core::default::Default::default();
-        $($crate::internal::register_event::<$event>(&mut events);)*
+        $(<$event as $crate::internal::CollectEvents>::register(
+            &mut events,
+            $crate::collect_events!(@name $event $(as $name)?),
+        );)*
         events
     }};
 }
+
+/// Collect requests and their types.
+///
+/// This returns a [`Requests`](crate::Requests) struct that can be passed to [`Builder::requests`](crate::Builder::requests).
+///
+/// # Usage
+/// ```rust
+/// use serde::{Serialize, Deserialize};
+/// use specta::Type;
+/// use tauri_specta::{Request, Builder, collect_requests};
+///
+/// #[derive(Serialize, Deserialize, Type, Request)]
+/// #[tauri_specta(response = String)]
+/// pub struct PickColor;
+///
+/// let mut builder = Builder::<tauri::Wry>::new().requests(collect_requests![PickColor]);
+/// ```
+///
+#[macro_export]
+macro_rules! collect_requests {
+    ($($request:path),* $(,)?) => {{
+        let mut requests: $crate::Requests = ::core::default::Default::default();
+        $($crate::internal::register_request::<$request>(&mut requests);)*
+        requests
+    }};
+}
+
+/// Define a Rust enum for the labels of your application's windows.
+///
+/// The generated enum can be passed to [`Builder::window_labels`](crate::Builder::window_labels) to export a matching `WindowLabel` type to the frontend,
+/// and can be used as the target of [`Event::emit_to`](crate::Event::emit_to).
+///
+/// # Usage
+/// ```rust
+/// use serde::{Serialize, Deserialize};
+/// use specta::Type;
+/// use tauri_specta::{Builder, Event, window_labels};
+///
+/// window_labels! {
+///     pub enum WindowLabel {
+///         Main = "main",
+///         Settings = "settings",
+///     }
+/// }
+///
+/// #[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+/// pub struct MyEvent(String);
+///
+/// let mut builder = Builder::<tauri::Wry>::new().window_labels(WindowLabel::ALL);
+///
+/// fn notify(app: &tauri::AppHandle) {
+///     MyEvent("Hello".into()).emit_to(app, WindowLabel::Settings).ok();
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! window_labels {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $label:literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                #[doc = concat!("The `", $label, "` window.")]
+                $(#[$variant_meta])*
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// All of the window labels.
+            pub const ALL: [Self; <[&str]>::len(&[$($label),*])] = [$(Self::$variant),*];
+
+            /// Get the label of the window.
+            pub const fn as_str(&self) -> &'static str {
+                match *self {
+                    $(Self::$variant => $label,)*
+                }
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl ::core::convert::From<$name> for ::std::borrow::Cow<'static, str> {
+            fn from(label: $name) -> Self {
+                ::std::borrow::Cow::Borrowed(label.as_str())
+            }
+        }
+
+        impl ::core::convert::From<$name> for ::tauri::EventTarget {
+            fn from(label: $name) -> Self {
+                ::tauri::EventTarget::labeled(label.as_str())
+            }
+        }
+    };
+}
@@ -0,0 +1,196 @@
+use std::{borrow::Cow, fmt, sync::Arc};
+
+use serde::Serialize;
+use tauri::{
+    Runtime, Webview,
+    ipc::{Invoke, InvokeBody},
+};
+
+use crate::name::resolve_tauri_command_name;
+
+/// Type-erased middleware registered using [`Builder::middleware`](crate::Builder::middleware).
+pub(crate) type MiddlewareFn<R> =
+    dyn Fn(InvokeContext<R>, Next<'_, R>) -> bool + Send + Sync + 'static;
+
+/// The middleware registered on a [`Builder`](crate::Builder).
+pub(crate) struct Middleware<R: Runtime>(pub(crate) Arc<[Arc<MiddlewareFn<R>>]>);
+
+impl<R: Runtime> Middleware<R> {
+    pub fn push(&mut self, middleware: Arc<MiddlewareFn<R>>) {
+        self.0 = self.0.iter().cloned().chain([middleware]).collect();
+    }
+}
+
+impl<R: Runtime> fmt::Debug for Middleware<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Middleware")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+impl<R: Runtime> Default for Middleware<R> {
+    fn default() -> Self {
+        Self(Arc::new([]))
+    }
+}
+
+impl<R: Runtime> Clone for Middleware<R> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// The error a command is rejected with by [`InvokeContext::reject`].
+///
+/// The generated bindings throw it as a `CommandRejected` error, so it can't be mistaken for an error returned by the command.
+#[derive(Debug, Clone, Serialize)]
+pub struct Rejection {
+    #[serde(rename = "__tauriSpectaRejected")]
+    message: String,
+}
+
+impl Rejection {
+    /// Construct a new rejection with a message explaining why the command was rejected.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// The message explaining why the command was rejected.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<&str> for Rejection {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<String> for Rejection {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+/// Information about a command invocation passed to [`Builder::middleware`](crate::Builder::middleware).
+pub struct InvokeContext<R: Runtime> {
+    invoke: Invoke<R>,
+    plugin_name: Option<&'static str>,
+}
+
+impl<R: Runtime> InvokeContext<R> {
+    pub(crate) fn new(invoke: Invoke<R>, plugin_name: Option<&'static str>) -> Self {
+        Self {
+            invoke,
+            plugin_name,
+        }
+    }
+
+    /// The name of the command being invoked, as it is declared in Rust.
+    pub fn command(&self) -> &str {
+        self.invoke.message.command()
+    }
+
+    /// The name the frontend used to invoke the command.
+    ///
+    /// This includes the `plugin:{name}|` prefix for plugin commands.
+    pub fn invoke_name(&self) -> Cow<'_, str> {
+        resolve_tauri_command_name(self.plugin_name, self.command())
+    }
+
+    /// The name of the plugin which the command belongs to, if it was registered using [`Builder::plugin_name`](crate::Builder::plugin_name).
+    pub fn plugin_name(&self) -> Option<&'static str> {
+        self.plugin_name
+    }
+
+    /// The webview which invoked the command.
+    pub fn webview(&self) -> &Webview<R> {
+        self.invoke.message.webview_ref()
+    }
+
+    /// The label of the webview which invoked the command.
+    pub fn webview_label(&self) -> &str {
+        self.webview().label()
+    }
+
+    /// The label of the window containing the webview which invoked the command.
+    pub fn window_label(&self) -> String {
+        self.webview().window().label().to_string()
+    }
+
+    /// The raw arguments of the command.
+    pub fn arguments(&self) -> &InvokeBody {
+        self.invoke.message.payload()
+    }
+
+    /// The underlying Tauri invoke.
+    pub fn invoke(&self) -> &Invoke<R> {
+        &self.invoke
+    }
+
+    /// Reject the command without running it.
+    ///
+    /// This always returns `true` so it can be returned directly from your middleware.
+    pub fn reject(self, rejection: impl Into<Rejection>) -> bool {
+        self.invoke.resolver.reject(rejection.into());
+        true
+    }
+
+    pub(crate) fn into_invoke(self) -> Invoke<R> {
+        self.invoke
+    }
+}
+
+impl<R: Runtime> fmt::Debug for InvokeContext<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InvokeContext")
+            .field("command", &self.command())
+            .field("plugin_name", &self.plugin_name)
+            .field("webview_label", &self.webview_label())
+            .finish()
+    }
+}
+
+/// The remainder of the middleware chain.
+///
+/// Call [`Next::run`] to continue handling the command.
+pub struct Next<'a, R: Runtime> {
+    middleware: &'a [Arc<MiddlewareFn<R>>],
+    handler: &'a dyn Fn(Invoke<R>) -> bool,
+}
+
+impl<'a, R: Runtime> Next<'a, R> {
+    pub(crate) fn new(
+        middleware: &'a [Arc<MiddlewareFn<R>>],
+        handler: &'a dyn Fn(Invoke<R>) -> bool,
+    ) -> Self {
+        Self {
+            middleware,
+            handler,
+        }
+    }
+
+    /// Run the next middleware, or the command itself if this is the last middleware.
+    ///
+    /// Returns whether the command was handled. This is `false` if the command doesn't exist.
+    ///
+    /// Synchronous commands have responded by the time this returns, while async commands have only been spawned onto the async runtime.
+    pub fn run(self, ctx: InvokeContext<R>) -> bool {
+        match self.middleware.split_first() {
+            Some((middleware, rest)) => middleware(ctx, Next::new(rest, self.handler)),
+            None => (self.handler)(ctx.into_invoke()),
+        }
+    }
+}
+
+impl<R: Runtime> fmt::Debug for Next<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Next")
+            .field("remaining", &self.middleware.len())
+            .finish()
+    }
+}
@@ -0,0 +1,56 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+};
+
+use serde::Serialize;
+use tauri::{Runtime, ipc::Invoke};
+
+/// The error payload a command rejects with when it panics.
+///
+/// The marker key is detected by the generated bindings and converted into a `CommandPanic`.
+#[derive(Serialize)]
+pub(crate) struct CommandPanic {
+    #[serde(rename = "__tauriSpectaPanic")]
+    pub message: String,
+}
+
+impl CommandPanic {
+    pub fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+/// Run the `dispatch` function, rejecting the invoke if it panics.
+///
+/// This only covers the synchronous part of the invocation. Async commands are spawned onto the async runtime,
+/// so panics inside them can't be caught here.
+pub(crate) fn catch_panic<R: Runtime>(
+    invoke: Invoke<R>,
+    dispatch: impl FnOnce(Invoke<R>) -> bool,
+) -> bool {
+    let resolver = invoke.resolver.clone();
+    match panic::catch_unwind(AssertUnwindSafe(|| dispatch(invoke))) {
+        Ok(handled) => handled,
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            #[cfg(feature = "tracing")]
+            {
+                tracing::error!(panic = %message, "tauri_specta::panic");
+                tracing::Span::current().record("outcome", "panicked");
+            }
+            resolver.reject(CommandPanic::new(message));
+            true
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Command panicked".into()
+    }
+}
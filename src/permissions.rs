@@ -0,0 +1,302 @@
+use std::{collections::BTreeSet, fs, io, path::Path};
+
+use crate::{BuilderConfiguration, LanguageExt, RESPOND_COMMAND, STICKY_COMMAND};
+
+const HEADER: &str = "# Automatically generated - DO NOT EDIT!\n";
+
+/// Generate [Tauri permission](https://v2.tauri.app/security/permissions/) files for the registered commands.
+///
+/// This is an alternative to the permissions generated by `tauri_plugin::Builder`, which requires maintaining the list of commands by hand.
+/// The path given to [`Builder::export`](crate::Builder::export) is your plugin's `permissions` directory. This will:
+///  - write an `allow-<command>` and `deny-<command>` permission for each command to `autogenerated/commands/<command>.toml`,
+///  - remove the generated files of commands which are no longer registered,
+///  - write a `default.toml` permission set which allows all commands (unless [`Permissions::default_commands`] or [`Permissions::without_default`] is used).
+///
+/// The command names are the ones the invoke handler sees, so disabled commands are excluded.
+/// The hidden [`STICKY_COMMAND`](crate::STICKY_COMMAND) and [`RESPOND_COMMAND`](crate::RESPOND_COMMAND) the bindings of [sticky](crate::Event::STICKY) events and [requests](crate::Request) use
+/// are included if needed and always allowed by the default set.
+///
+/// # Example
+///
+/// Export the permissions from your plugin's `build.rs` before running `tauri_plugin::Builder`, so they're regenerated on every build.
+/// Pass it an empty list of commands so they are not generated twice.
+/// The commands must be defined in a module the build script can include, like `src/commands.rs` below.
+///
+/// ```rust,ignore
+/// // build.rs
+/// #[path = "src/commands.rs"]
+/// mod commands;
+///
+/// fn main() {
+///     println!("cargo:rerun-if-changed=src/commands.rs");
+///
+///     commands::builder::<tauri::test::MockRuntime>()
+///         .export(tauri_specta::Permissions::default(), "./permissions")
+///         .expect("Failed to export permissions");
+///
+///     tauri_plugin::Builder::new(&[]).build();
+/// }
+/// ```
+///
+/// The build script needs `tauri` with the `test` feature, `tauri-specta` and anything else `src/commands.rs` uses as build dependencies.
+/// See the `custom-plugin` example for a complete setup.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct Permissions {
+    default: DefaultSet,
+}
+
+#[derive(Debug, Clone, Default)]
+enum DefaultSet {
+    #[default]
+    All,
+    Only(BTreeSet<String>),
+    Skip,
+}
+
+impl Permissions {
+    /// Only allow the given commands in the default permission set.
+    pub fn default_commands<S: Into<String>>(
+        mut self,
+        commands: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.default = DefaultSet::Only(commands.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Don't generate the default permission set.
+    ///
+    /// Use this if you maintain your own `default.toml`.
+    pub fn without_default(mut self) -> Self {
+        self.default = DefaultSet::Skip;
+        self
+    }
+}
+
+impl LanguageExt for Permissions {
+    type Error = io::Error;
+
+    fn export(self, cfg: &BuilderConfiguration, path: &Path) -> Result<(), Self::Error> {
+        let commands_dir = path.join("autogenerated").join("commands");
+        fs::create_dir_all(&commands_dir)?;
+
+        // Sticky events are read and requests are answered through hidden commands, which need permissions too.
+        let hidden = [
+            (
+                cfg.event_definitions.values().any(|event| event.sticky),
+                STICKY_COMMAND,
+            ),
+            (!cfg.requests.is_empty(), RESPOND_COMMAND),
+        ]
+        .into_iter()
+        .filter_map(|(registered, command)| registered.then_some(command))
+        .collect::<Vec<_>>();
+        let commands = cfg
+            .commands
+            .iter()
+            .map(|command| command.name())
+            .chain(hidden.iter().copied())
+            .collect::<Vec<_>>();
+
+        let schema = path.join("schemas").join("schema.json").exists();
+        let mut files = BTreeSet::new();
+        for command in &commands {
+            let file = commands_dir.join(format!("{command}.toml"));
+            write_if_changed(&file, &command_permissions(command, schema))?;
+            files.insert(file);
+        }
+
+        // Remove the permissions of commands which are no longer registered.
+        // Files without our header were written by hand, so they are left alone.
+        for entry in fs::read_dir(&commands_dir)? {
+            let file = entry?.path();
+            if file.extension().is_some_and(|ext| ext == "toml")
+                && !files.contains(&file)
+                && generated(&file)
+            {
+                fs::remove_file(file)?;
+            }
+        }
+
+        let default = match self.default {
+            DefaultSet::All => Some(commands.iter().map(|command| command.to_string()).collect()),
+            // The generated bindings rely on the hidden commands, so they're always allowed.
+            DefaultSet::Only(default) => Some(
+                default
+                    .into_iter()
+                    .chain(hidden.iter().map(|command| command.to_string()))
+                    .collect(),
+            ),
+            DefaultSet::Skip => None,
+        };
+        if let Some(default) = default {
+            if let Some(unknown) = default
+                .iter()
+                .find(|name| !commands.contains(&name.as_str()))
+            {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("Default permission set references unknown command '{unknown}'"),
+                ));
+            }
+
+            write_default(&path.join("default.toml"), &default)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The identifier of a permission, matching what `tauri_plugin::Builder` generates.
+pub(crate) fn permission_identifier(action: &str, command: &str) -> String {
+    format!("{action}-{}", command.replace('_', "-"))
+}
+
+/// The identifier of the permission which allows calling the plugin's command, as it's referenced in capabilities.
+pub(crate) fn required_permission(plugin_name: &str, command: &str) -> String {
+    format!("{plugin_name}:{}", permission_identifier("allow", command))
+}
+
+fn command_permissions(command: &str, schema: bool) -> String {
+    let mut out = HEADER.to_string();
+    if schema {
+        out.push_str("\n\"$schema\" = \"../../schemas/schema.json\"\n");
+    }
+    for (action, description) in [("allow", "Enables"), ("deny", "Denies")] {
+        out.push_str(&format!(
+            "\n[[permission]]\nidentifier = {}\ndescription = {}\ncommands.{action} = [{}]\n",
+            toml_string(&permission_identifier(action, command)),
+            toml_string(&format!(
+                "{description} the {command} command without any pre-configured scope."
+            )),
+            toml_string(command),
+        ));
+    }
+    out
+}
+
+fn write_default(file: &Path, commands: &BTreeSet<String>) -> io::Result<()> {
+    // We don't want to overwrite a default permission set written by hand.
+    if file.exists() && !generated(file) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!(
+                "{} was not generated by Tauri Specta. Remove it or use `Permissions::without_default`.",
+                file.display()
+            ),
+        ));
+    }
+
+    let permissions = commands
+        .iter()
+        .map(|command| {
+            format!(
+                "  {},\n",
+                toml_string(&permission_identifier("allow", command))
+            )
+        })
+        .collect::<String>();
+    write_if_changed(
+        file,
+        &format!(
+            "{HEADER}\n[default]\ndescription = \"Default permissions for the plugin\"\npermissions = [\n{permissions}]\n"
+        ),
+    )
+}
+
+/// Whether the file was written by this exporter.
+fn generated(file: &Path) -> bool {
+    fs::read_to_string(file).is_ok_and(|contents| contents.starts_with(HEADER))
+}
+
+// TOML basic strings use the same escapes as JSON.
+fn toml_string(s: &str) -> String {
+    serde_json::to_string(s).expect("failed to serialize string")
+}
+
+// Avoid touching files which haven't changed so `build.rs` doesn't rerun.
+fn write_if_changed(file: &Path, contents: &str) -> io::Result<()> {
+    if fs::read_to_string(file).is_ok_and(|existing| existing == contents) {
+        return Ok(());
+    }
+    fs::write(file, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_generated_files_are_removed() {
+        let dir = Path::new("target/tests/permissions");
+        let _ = fs::remove_dir_all(dir);
+        let commands_dir = dir.join("autogenerated").join("commands");
+        fs::create_dir_all(&commands_dir).expect("failed to create directory");
+        fs::write(
+            commands_dir.join("removed.toml"),
+            command_permissions("removed", false),
+        )
+        .expect("failed to write permissions");
+        fs::write(commands_dir.join("custom.toml"), "[[permission]]\n")
+            .expect("failed to write permissions");
+
+        Permissions::default()
+            .without_default()
+            .export(&BuilderConfiguration::default(), dir)
+            .expect("failed to export permissions");
+
+        assert!(!commands_dir.join("removed.toml").exists());
+        assert!(commands_dir.join("custom.toml").exists());
+    }
+
+    #[derive(Clone, serde::Serialize, specta::Type)]
+    struct Status(String);
+
+    impl crate::Event for Status {
+        const NAME: &'static str = "status";
+        const STICKY: bool = true;
+    }
+
+    #[test]
+    fn sticky_command_is_allowed_by_default() {
+        let dir = Path::new("target/tests/sticky-permissions");
+        let _ = fs::remove_dir_all(dir);
+
+        crate::Builder::<tauri::Wry>::new()
+            .events(crate::collect_events![Status])
+            .export(Permissions::default().default_commands::<&str>([]), dir)
+            .expect("failed to export permissions");
+
+        assert_eq!(
+            fs::read_to_string(dir.join("autogenerated/commands/__tauri_specta_sticky.toml"))
+                .expect("failed to read permissions"),
+            command_permissions(STICKY_COMMAND, false)
+        );
+        assert!(
+            fs::read_to_string(dir.join("default.toml"))
+                .expect("failed to read default permissions")
+                .contains("\"allow---tauri-specta-sticky\"")
+        );
+    }
+
+    #[test]
+    fn command_permissions_match_tauri_plugin() {
+        assert_eq!(
+            command_permissions("add_numbers", true),
+            r#"# Automatically generated - DO NOT EDIT!
+
+"$schema" = "../../schemas/schema.json"
+
+[[permission]]
+identifier = "allow-add-numbers"
+description = "Enables the add_numbers command without any pre-configured scope."
+commands.allow = ["add_numbers"]
+
+[[permission]]
+identifier = "deny-add-numbers"
+description = "Denies the add_numbers command without any pre-configured scope."
+commands.deny = ["add_numbers"]
+"#
+        );
+    }
+}
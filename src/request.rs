@@ -0,0 +1,299 @@
+use std::{
+    any::TypeId,
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    hash::{BuildHasher, Hasher, RandomState},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+use specta::{Type, Types, datatype::DataType};
+use tauri::{
+    App, AppHandle, Emitter, EventTarget, Listener, Manager, Runtime, Webview, WebviewWindow,
+    Window,
+    async_runtime::{JoinHandle, Receiver, Sender},
+};
+
+use crate::{EventRegistry, event::target_matches, name::resolve_tauri_event_name};
+
+/// The name of the hidden command the generated bindings respond to a [`Request`] with.
+pub const RESPOND_COMMAND: &str = "__tauri_specta_respond";
+
+/// A wrapper around the output of the `collect_requests` macro.
+///
+/// This acts to seal the implementation details of the macro.
+#[derive(Clone, Default)]
+#[allow(clippy::type_complexity)]
+pub struct Requests(
+    pub(crate) BTreeMap<&'static str, Arc<dyn Fn(&mut Types) -> RequestDefinition + Send + Sync>>,
+);
+
+/// The metadata of a request registered with the [`Builder`](crate::Builder).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RequestDefinition {
+    /// The type of the request.
+    pub type_id: TypeId,
+    /// The data type of the request payload.
+    pub request: DataType,
+    /// The data type of the response.
+    pub response: DataType,
+    /// The documentation of the request.
+    pub docs: Cow<'static, str>,
+}
+
+/// The error returned when [asking](Request::ask) the frontend fails.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum RequestError {
+    /// The frontend didn't respond within the timeout, e.g. because no handler is registered.
+    #[error("The request was not answered within {0:?}")]
+    Timeout(Duration),
+    /// The handler in the frontend threw an error.
+    #[error("The request handler failed: {0}")]
+    Handler(String),
+    /// The request couldn't be emitted.
+    #[error(transparent)]
+    Tauri(#[from] tauri::Error),
+    /// The request couldn't be serialized or the response couldn't be deserialized.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+/// Something a [`Request`] can be sent to.
+///
+/// Requests sent to a window or webview are only handled by the webviews with its label, and responses from other webviews are ignored.
+/// Requests sent to the app are handled by every webview with a handler, and the first response is used.
+pub trait Responder<R: Runtime>: Emitter<R> + Listener<R> + Manager<R> {
+    /// The target the request is emitted to.
+    fn target(&self) -> EventTarget;
+}
+
+impl<R: Runtime> Responder<R> for App<R> {
+    fn target(&self) -> EventTarget {
+        EventTarget::Any
+    }
+}
+
+impl<R: Runtime> Responder<R> for AppHandle<R> {
+    fn target(&self) -> EventTarget {
+        EventTarget::Any
+    }
+}
+
+impl<R: Runtime> Responder<R> for Window<R> {
+    fn target(&self) -> EventTarget {
+        EventTarget::window(self.label())
+    }
+}
+
+impl<R: Runtime> Responder<R> for Webview<R> {
+    fn target(&self) -> EventTarget {
+        EventTarget::webview(self.label())
+    }
+}
+
+impl<R: Runtime> Responder<R> for WebviewWindow<R> {
+    fn target(&self) -> EventTarget {
+        EventTarget::webview_window(self.label())
+    }
+}
+
+/// Extends your request type with a typesafe method for asking the frontend for a response.
+///
+/// You should rely on the [`Request`](macro@crate::Request) derive macro to implement this for you.
+///
+/// The request is emitted as an event, and the frontend responds using `requests.myRequest.handle(cb)` in the generated bindings.
+/// Each request carries an ID so concurrent requests are matched with their responses.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use serde::{Deserialize, Serialize};
+/// use specta::Type;
+/// use tauri_specta::{Builder, Request, collect_requests};
+///
+/// #[derive(Serialize, Deserialize, Type, Request)]
+/// #[tauri_specta(response = bool)]
+/// pub struct ConfirmDelete {
+///     path: String,
+/// }
+///
+/// let builder = Builder::<tauri::Wry>::new().requests(collect_requests![ConfirmDelete]);
+///
+/// async fn delete(window: tauri::WebviewWindow, path: String) -> Result<(), tauri_specta::RequestError> {
+///     let confirmed = ConfirmDelete { path: path.clone() }
+///         .ask(&window, Duration::from_secs(30))
+///         .await?;
+///     if confirmed {
+///         // ...
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait Request: Type + Serialize + Sized + 'static {
+    /// The name of the event the request is emitted as.
+    const NAME: &'static str;
+
+    /// The documentation of the request, which is rendered in the generated bindings.
+    /// This is only used if the type has no doc comments, which are rendered instead.
+    ///
+    /// The [`Request`](macro@crate::Request) derive macro sets this from the type's doc comments.
+    const DOCS: &'static str = "";
+
+    /// The type the frontend responds with.
+    type Response: Type + DeserializeOwned + Send + 'static;
+
+    /// Emit the request to the responder and wait for the frontend's response.
+    ///
+    /// This fails with [`RequestError::Timeout`] if no response is received within the timeout.
+    fn ask<R: Runtime, H: Responder<R>>(
+        &self,
+        handle: &H,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<Self::Response, RequestError>> + Send {
+        let pending = send(self, handle, timeout);
+        async move { pending?.recv().await }
+    }
+}
+
+#[derive(Serialize)]
+struct RequestPayload<'a, T> {
+    id: &'a str,
+    payload: &'a T,
+}
+
+/// The requests waiting for a response from the frontend, which is put into Tauri's state.
+#[derive(Default)]
+pub(crate) struct RequestRegistry {
+    pending: Mutex<HashMap<String, PendingRequest>>,
+}
+
+struct PendingRequest {
+    target: EventTarget,
+    sender: Sender<Result<serde_json::Value, RequestError>>,
+}
+
+impl RequestRegistry {
+    /// Pass the webview's response to the request with the ID.
+    ///
+    /// Returns `false` if there is no such request or it wasn't sent to the webview.
+    pub fn respond(
+        &self,
+        id: &str,
+        webview: &str,
+        window: &str,
+        response: Result<serde_json::Value, String>,
+    ) -> bool {
+        let mut pending = self.pending.lock().expect("Failed to lock RequestRegistry");
+        if !pending
+            .get(id)
+            .is_some_and(|request| target_matches(&request.target, webview, window))
+        {
+            return false;
+        }
+        if let Some(request) = pending.remove(id) {
+            let _ = request
+                .sender
+                .try_send(response.map_err(RequestError::Handler));
+        }
+        true
+    }
+
+    fn get_or_manage<R: Runtime>(handle: &impl Manager<R>) -> tauri::State<'_, Self> {
+        if handle.try_state::<Self>().is_none() {
+            handle.manage(Self::default());
+        }
+
+        handle.state::<Self>()
+    }
+}
+
+/// A response which hasn't been received yet.
+struct Pending<R: Runtime> {
+    id: String,
+    receiver: Receiver<Result<serde_json::Value, RequestError>>,
+    timeout: Duration,
+    timer: JoinHandle<()>,
+    app: AppHandle<R>,
+}
+
+impl<R: Runtime> Pending<R> {
+    async fn recv<T: DeserializeOwned>(mut self) -> Result<T, RequestError> {
+        let response = self
+            .receiver
+            .recv()
+            .await
+            .unwrap_or(Err(RequestError::Timeout(self.timeout)))?;
+        Ok(serde_json::from_value(response)?)
+    }
+}
+
+impl<R: Runtime> Drop for Pending<R> {
+    fn drop(&mut self) {
+        self.timer.abort();
+        RequestRegistry::get_or_manage(&self.app)
+            .pending
+            .lock()
+            .expect("Failed to lock RequestRegistry")
+            .remove(&self.id);
+    }
+}
+
+/// A random ID, so a webview can't answer requests it wasn't sent by guessing their ID.
+///
+/// This is a string as JavaScript numbers can't represent every `u64`.
+fn request_id() -> String {
+    let random = || RandomState::new().build_hasher().finish();
+    format!("{:016x}{:016x}", random(), random())
+}
+
+fn send<Q: Request, R: Runtime, H: Responder<R>>(
+    request: &Q,
+    handle: &H,
+    timeout: Duration,
+) -> Result<Pending<R>, RequestError> {
+    let (plugin_name, name) = EventRegistry::get_registered::<Q, R>(handle, Q::NAME);
+    let name = resolve_tauri_event_name(plugin_name, &name).into_owned();
+    let id = request_id();
+    let target = handle.target();
+    let (sender, receiver) = tauri::async_runtime::channel(1);
+
+    // The request is registered before it's emitted so a response can't be missed.
+    RequestRegistry::get_or_manage(handle)
+        .pending
+        .lock()
+        .expect("Failed to lock RequestRegistry")
+        .insert(
+            id.clone(),
+            PendingRequest {
+                target: target.clone(),
+                sender: sender.clone(),
+            },
+        );
+    let timer = tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(timeout).await;
+        let _ = sender.try_send(Err(RequestError::Timeout(timeout)));
+    });
+    let pending = Pending {
+        id,
+        receiver,
+        timeout,
+        timer,
+        app: handle.app_handle().clone(),
+    };
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(event = %name, id = %pending.id, "tauri_specta::ask");
+    let payload = serde_json::to_value(RequestPayload {
+        id: &pending.id,
+        payload: request,
+    })?;
+    handle.emit_to(target, &name, payload)?;
+
+    Ok(pending)
+}
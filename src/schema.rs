@@ -0,0 +1,250 @@
+use std::borrow::Cow;
+
+use heck::ToLowerCamelCase;
+use serde_json::{Map, Value, json};
+use specta::{
+    Format, Types,
+    datatype::{DataType, Fields, NamedReferenceType, Primitive, Reference},
+};
+
+use crate::{
+    BuilderConfiguration, EventDirection,
+    name::{resolve_tauri_command_name, resolve_tauri_event_name},
+};
+
+/// The name of the hidden command registered by [`Builder::expose_schema`](crate::Builder::expose_schema).
+pub const SCHEMA_COMMAND: &str = "__tauri_specta_schema";
+
+/// Build the schema returned by the [`SCHEMA_COMMAND`].
+///
+/// Serde attributes are applied to the types where possible.
+/// If they can't be (e.g. a type serializes and deserializes differently) the types are described as Specta collected them.
+pub(crate) fn schema(cfg: &BuilderConfiguration) -> Value {
+    let formatted = specta_serde::Format
+        .map_types(&cfg.types)
+        .map(Cow::into_owned)
+        .ok();
+    let types = formatted.as_ref().unwrap_or(&cfg.types);
+    let describe_dt = |dt: &DataType| {
+        let formatted_dt = formatted.as_ref().and_then(|_| {
+            specta_serde::Format
+                .map_type(&cfg.types, dt)
+                .map(Cow::into_owned)
+                .ok()
+        });
+        describe(formatted_dt.as_ref().unwrap_or(dt), types)
+    };
+
+    let commands = cfg
+        .commands
+        .iter()
+        .map(|command| {
+            json!({
+                "name": command.name(),
+                "invokeName": resolve_tauri_command_name(cfg.plugin_name, command.name()),
+                "args": command
+                    .args()
+                    .iter()
+                    .map(|(name, dt)| {
+                        // Tauri expects arguments in camel case by default.
+                        json!({ "name": name.to_lower_camel_case(), "type": describe_dt(dt) })
+                    })
+                    .collect::<Vec<_>>(),
+                "result": command.result().map(describe_dt),
+                "docs": command.docs.to_string(),
+                "deprecated": command.deprecated.is_some(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let events = cfg
+        .event_definitions
+        .iter()
+        .map(|(name, event)| {
+            json!({
+                "name": name,
+                "eventName": resolve_tauri_event_name(cfg.plugin_name, name),
+                "type": describe_dt(&event.data_type),
+                "group": event.group,
+                "docs": event.docs,
+                "deprecated": event.deprecated.is_some(),
+                "sticky": event.sticky,
+                "direction": match event.direction {
+                    EventDirection::ToFrontend => "toFrontend",
+                    EventDirection::ToBackend => "toBackend",
+                    EventDirection::Both => "both",
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let requests = cfg
+        .requests
+        .iter()
+        .map(|(name, request)| {
+            json!({
+                "name": name,
+                "eventName": resolve_tauri_event_name(cfg.plugin_name, name),
+                "request": describe_dt(&request.request),
+                "response": describe_dt(&request.response),
+                "docs": request.docs,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut named_types = types
+        .into_unsorted_iter()
+        .filter_map(|ndt| {
+            ndt.ty.as_ref().map(|dt| {
+                json!({
+                    "name": ndt.name,
+                    "modulePath": ndt.module_path,
+                    "type": describe(dt, types),
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+    named_types.sort_by(|a, b| {
+        (a["modulePath"].as_str(), a["name"].as_str())
+            .cmp(&(b["modulePath"].as_str(), b["name"].as_str()))
+    });
+
+    let mut schema = json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "pluginName": cfg.plugin_name,
+        "commands": commands,
+        "events": events,
+        "requests": requests,
+        "constants": cfg.constants,
+        "types": named_types,
+    });
+    let hash = fnv1a(schema.to_string().as_bytes());
+    schema["hash"] = Value::String(format!("{hash:016x}"));
+    schema
+}
+
+/// The 64-bit FNV-1a hash.
+///
+/// We can't use the standard library hasher as its output is not stable across Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn describe(dt: &DataType, types: &Types) -> Value {
+    match dt {
+        DataType::Primitive(p) => json!({ "kind": "primitive", "type": primitive_name(p) }),
+        // The name of the generic parameter as it's declared in Rust (e.g. `T`).
+        DataType::Generic(g) => json!({ "kind": "generic", "name": g.to_string() }),
+        DataType::List(list) => json!({ "kind": "list", "item": describe(&list.ty, types) }),
+        DataType::Map(map) => json!({
+            "kind": "map",
+            "key": describe(map.key_ty(), types),
+            "value": describe(map.value_ty(), types),
+        }),
+        DataType::Nullable(dt) => json!({ "kind": "nullable", "type": describe(dt, types) }),
+        DataType::Tuple(tuple) => json!({
+            "kind": "tuple",
+            "elements": tuple.elements.iter().map(|dt| describe(dt, types)).collect::<Vec<_>>(),
+        }),
+        DataType::Struct(s) => {
+            json!({ "kind": "struct", "fields": describe_fields(&s.fields, types) })
+        }
+        DataType::Enum(e) => json!({
+            "kind": "enum",
+            "variants": e
+                .variants
+                .iter()
+                .filter(|(_, variant)| !variant.skip)
+                .map(|(name, variant)| (name.to_string(), describe_fields(&variant.fields, types)))
+                .collect::<Map<_, _>>(),
+        }),
+        DataType::Intersection(dts) => json!({
+            "kind": "intersection",
+            "types": dts.iter().map(|dt| describe(dt, types)).collect::<Vec<_>>(),
+        }),
+        DataType::Reference(Reference::Named(r)) => {
+            let name = types.get(r).map(|ndt| ndt.name.to_string());
+            match &r.inner {
+                NamedReferenceType::Inline { dt, .. } => describe(dt, types),
+                NamedReferenceType::Reference { generics, .. } => json!({
+                    "kind": "reference",
+                    "name": name,
+                    "generics": generics
+                        .iter()
+                        .map(|(_, dt)| describe(dt, types))
+                        .collect::<Vec<_>>(),
+                }),
+                NamedReferenceType::Recursive(_) => json!({ "kind": "reference", "name": name }),
+            }
+        }
+        DataType::Reference(Reference::Opaque(_)) => json!({ "kind": "opaque" }),
+    }
+}
+
+/// The name of the primitive as it's written in Rust (e.g. `u32` or `String`).
+///
+/// This is part of the schema so it must not depend on the [`Debug`] output of Specta's types.
+fn primitive_name(primitive: &Primitive) -> &'static str {
+    match primitive {
+        Primitive::i8 => "i8",
+        Primitive::i16 => "i16",
+        Primitive::i32 => "i32",
+        Primitive::i64 => "i64",
+        Primitive::i128 => "i128",
+        Primitive::isize => "isize",
+        Primitive::u8 => "u8",
+        Primitive::u16 => "u16",
+        Primitive::u32 => "u32",
+        Primitive::u64 => "u64",
+        Primitive::u128 => "u128",
+        Primitive::usize => "usize",
+        Primitive::f16 => "f16",
+        Primitive::f32 => "f32",
+        Primitive::f64 => "f64",
+        Primitive::f128 => "f128",
+        Primitive::bool => "bool",
+        Primitive::char => "char",
+        Primitive::str => "str",
+        Primitive::String => "String",
+        #[allow(unreachable_patterns)]
+        _ => "unknown",
+    }
+}
+
+fn describe_fields(fields: &Fields, types: &Types) -> Value {
+    match fields {
+        Fields::Unit => json!({ "kind": "unit" }),
+        Fields::Unnamed(fields) => json!({
+            "kind": "unnamed",
+            "fields": fields
+                .fields
+                .iter()
+                .map(|field| field.ty.as_ref().map(|dt| describe(dt, types)))
+                .collect::<Vec<_>>(),
+        }),
+        Fields::Named(fields) => json!({
+            "kind": "named",
+            "fields": fields
+                .fields
+                .iter()
+                .filter_map(|(name, field)| {
+                    field.ty.as_ref().map(|dt| (name.to_string(), describe(dt, types)))
+                })
+                .collect::<Map<_, _>>(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fnv1a_matches_reference_values() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x85944171f73967e8);
+    }
+}
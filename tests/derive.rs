@@ -0,0 +1,45 @@
+#![cfg(feature = "derive")]
+#![allow(missing_docs)]
+
+use serde::Serialize;
+use specta::Type;
+use tauri_specta::{Event, Request};
+
+/// Sent while a download is in progress.
+///
+/// The payload is the percentage downloaded.
+#[derive(Clone, Serialize, Type, Event)]
+#[deprecated(note = "Use `DownloadStatus` instead")]
+struct DownloadProgress(u8);
+
+#[derive(Clone, Serialize, Type, Event)]
+struct Undocumented;
+
+/// Asks the user to confirm deleting the file.
+#[derive(Serialize, Type, Request)]
+#[tauri_specta(response = bool)]
+struct ConfirmDelete(String);
+
+#[test]
+#[allow(deprecated)]
+fn event_docs_and_deprecation_are_derived() {
+    assert_eq!(
+        DownloadProgress::DOCS,
+        "Sent while a download is in progress.\n\nThe payload is the percentage downloaded."
+    );
+    assert_eq!(
+        DownloadProgress::DEPRECATED,
+        Some("Use `DownloadStatus` instead")
+    );
+
+    assert_eq!(Undocumented::DOCS, "");
+    assert_eq!(Undocumented::DEPRECATED, None);
+}
+
+#[test]
+fn request_docs_are_derived() {
+    assert_eq!(
+        ConfirmDelete::DOCS,
+        "Asks the user to confirm deleting the file."
+    );
+}
@@ -0,0 +1,82 @@
+#![cfg(all(feature = "typescript", feature = "derive"))]
+#![allow(missing_docs, clippy::unwrap_used)]
+
+use std::{
+    fs,
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use specta_typescript::Typescript;
+use tauri::test::{MockRuntime, mock_builder, mock_context, noop_assets};
+use tauri_specta::{Builder, EventGroup, collect_events};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+struct DownloadFailed {
+    reason: String,
+}
+
+#[derive(Debug, PartialEq, EventGroup)]
+enum Download {
+    Started(u32),
+    Failed(DownloadFailed),
+    Finished,
+}
+
+#[test]
+fn event_group_exports_nested_events() {
+    let dir = std::env::temp_dir().join(format!("tauri_specta_event_group_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("bindings.ts");
+    Builder::<MockRuntime>::new()
+        .events(collect_events![Download])
+        .export(Typescript::default(), &path)
+        .expect("failed to export bindings");
+    let out = fs::read_to_string(&path).unwrap();
+
+    assert!(
+        out.contains("download: {"),
+        "expected a nested object, got:\n{out}"
+    );
+    assert!(out.contains(r#"started: makeEvent<number, number>("download:started")"#));
+    assert!(
+        out.contains(r#"failed: makeEvent<DownloadFailed, DownloadFailed>("download:failed")"#)
+    );
+    assert!(out.contains(r#"finished: makeEvent<null, null>("download:finished")"#));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn event_group_emits_and_listens_to_each_variant() {
+    let builder = Builder::<MockRuntime>::new().events(collect_events![Download]);
+    let app = mock_builder().build(mock_context(noop_assets())).unwrap();
+    builder.mount_events(&app);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let ids = Download::listen(&app, {
+        let received = received.clone();
+        move |event| received.lock().unwrap().push(event.payload)
+    });
+    assert_eq!(ids.len(), 3);
+
+    Download::Started(1).emit(&app).unwrap();
+    Download::Failed(DownloadFailed {
+        reason: "offline".into(),
+    })
+    .emit(&app)
+    .unwrap();
+    Download::Finished.emit(&app).unwrap();
+
+    assert_eq!(
+        *received.lock().unwrap(),
+        vec![
+            Download::Started(1),
+            Download::Failed(DownloadFailed {
+                reason: "offline".into()
+            }),
+            Download::Finished,
+        ]
+    );
+}
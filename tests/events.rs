@@ -0,0 +1,144 @@
+#![allow(missing_docs, clippy::unwrap_used)]
+
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tauri::{
+    Listener, WebviewWindow, WebviewWindowBuilder,
+    ipc::{CallbackFn, InvokeBody},
+    test::{INVOKE_KEY, MockRuntime, get_ipc_response, mock_builder, mock_context, noop_assets},
+    webview::InvokeRequest,
+};
+use tauri_specta::{
+    Builder, Event, RESPOND_COMMAND, Request, RequestError, collect_events, collect_requests,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+struct Progress(u32);
+
+impl Event for Progress {
+    const NAME: &'static str = "progress";
+    const THROTTLE: Option<Duration> = Some(Duration::from_millis(50));
+}
+
+#[derive(Serialize, specta::Type)]
+struct ConfirmDelete(String);
+
+impl Request for ConfirmDelete {
+    const NAME: &'static str = "confirm-delete";
+    type Response = bool;
+}
+
+#[test]
+fn throttled_events_deliver_the_first_and_last_payload() {
+    let builder = Builder::<MockRuntime>::new().events(collect_events![Progress]);
+    let app = mock_builder().build(mock_context(noop_assets())).unwrap();
+    builder.mount_events(&app);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    Progress::listen(&app, {
+        let received = received.clone();
+        move |event| received.lock().unwrap().push(event.payload.0)
+    });
+
+    for i in 0..=100 {
+        Progress(i).emit(&app).unwrap();
+    }
+    thread::sleep(Duration::from_millis(200));
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.first(), Some(&0));
+    assert_eq!(received.last(), Some(&100));
+    assert!(
+        received.len() < 10,
+        "expected bursts to be coalesced, got {received:?}"
+    );
+}
+
+fn respond(
+    webview: &WebviewWindow<MockRuntime>,
+    body: serde_json::Value,
+) -> Result<serde_json::Value, serde_json::Value> {
+    get_ipc_response(
+        webview,
+        InvokeRequest {
+            cmd: RESPOND_COMMAND.into(),
+            callback: CallbackFn(0),
+            error: CallbackFn(1),
+            url: if cfg!(any(windows, target_os = "android")) {
+                "http://tauri.localhost"
+            } else {
+                "tauri://localhost"
+            }
+            .parse()
+            .unwrap(),
+            body: InvokeBody::Json(body),
+            headers: Default::default(),
+            invoke_key: INVOKE_KEY.to_string(),
+        },
+    )
+    .map(|body| body.deserialize().unwrap())
+}
+
+#[test]
+fn requests_are_answered_by_the_frontend() {
+    let builder = Builder::<MockRuntime>::new().requests(collect_requests![ConfirmDelete]);
+    let app = mock_builder()
+        .invoke_handler(builder.invoke_handler())
+        .build(mock_context(noop_assets()))
+        .unwrap();
+    builder.mount_events(&app);
+    let main = WebviewWindowBuilder::new(&app, "main", Default::default())
+        .build()
+        .unwrap();
+    let other = WebviewWindowBuilder::new(&app, "other", Default::default())
+        .build()
+        .unwrap();
+
+    // Respond like the generated `handle` does.
+    app.listen_any("confirm-delete", {
+        let main = main.clone();
+        move |event| {
+            let request: serde_json::Value = serde_json::from_str(event.payload()).unwrap();
+            assert!(request["id"].is_string());
+            // Only the webview the request was sent to can answer it.
+            assert!(respond(&other, json!({ "id": request["id"], "response": false })).is_err());
+
+            let response = if request["payload"] == "/" {
+                json!({ "id": request["id"], "error": "Refusing to delete /" })
+            } else {
+                json!({ "id": request["id"], "response": true })
+            };
+            respond(&main, response.clone()).unwrap();
+            // Only the first response is used.
+            assert!(respond(&main, response).is_err());
+        }
+    });
+
+    let ask = |path: &str| {
+        tauri::async_runtime::block_on(
+            ConfirmDelete(path.into()).ask(&main, Duration::from_secs(1)),
+        )
+    };
+    assert!(matches!(ask("/tmp/file"), Ok(true)));
+    assert!(
+        matches!(ask("/"), Err(RequestError::Handler(error)) if error == "Refusing to delete /")
+    );
+}
+
+#[test]
+fn unanswered_requests_time_out() {
+    let builder = Builder::<MockRuntime>::new().requests(collect_requests![ConfirmDelete]);
+    let app = mock_builder().build(mock_context(noop_assets())).unwrap();
+    builder.mount_events(&app);
+
+    let result = tauri::async_runtime::block_on(
+        ConfirmDelete("/tmp/file".into()).ask(app.handle(), Duration::from_millis(10)),
+    );
+    assert!(matches!(result, Err(RequestError::Timeout(_))));
+}
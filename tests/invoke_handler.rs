@@ -0,0 +1,410 @@
+#![allow(missing_docs, clippy::unwrap_used, clippy::panic)]
+
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use serde_json::json;
+use tauri::{
+    App, WebviewWindow, WebviewWindowBuilder,
+    ipc::{CallbackFn, InvokeBody},
+    test::{INVOKE_KEY, MockRuntime, get_ipc_response, mock_builder, mock_context, noop_assets},
+    webview::InvokeRequest,
+};
+use tauri_specta::{
+    Builder, Event, SCHEMA_COMMAND, STICKY_COMMAND, collect_commands, collect_events,
+};
+
+#[tauri::command]
+#[specta::specta]
+fn hello_world(my_name: String) -> String {
+    format!("Hello, {my_name}!")
+}
+
+#[tauri::command]
+#[specta::specta]
+fn explode() -> String {
+    panic!("boom")
+}
+
+#[tauri::command]
+#[specta::specta]
+fn sum(values: Vec<u8>) -> u32 {
+    values.into_iter().map(u32::from).sum()
+}
+
+#[tauri::command(rename_all = "snake_case")]
+#[specta::specta]
+fn greet_user(user_name: String) -> String {
+    format!("Hello, {user_name}!")
+}
+
+#[derive(Clone, Serialize, specta::Type)]
+struct Status(String);
+
+impl Event for Status {
+    const NAME: &'static str = "status";
+    const STICKY: bool = true;
+}
+
+fn mock_app(builder: &Builder<MockRuntime>) -> (App<MockRuntime>, WebviewWindow<MockRuntime>) {
+    let app = mock_builder()
+        .invoke_handler(builder.invoke_handler())
+        .build(mock_context(noop_assets()))
+        .unwrap();
+    let webview = WebviewWindowBuilder::new(&app, "main", Default::default())
+        .build()
+        .unwrap();
+    (app, webview)
+}
+
+fn invoke(
+    webview: &WebviewWindow<MockRuntime>,
+    cmd: &str,
+    body: serde_json::Value,
+) -> Result<serde_json::Value, serde_json::Value> {
+    get_ipc_response(
+        webview,
+        InvokeRequest {
+            cmd: cmd.into(),
+            callback: CallbackFn(0),
+            error: CallbackFn(1),
+            url: if cfg!(any(windows, target_os = "android")) {
+                "http://tauri.localhost"
+            } else {
+                "tauri://localhost"
+            }
+            .parse()
+            .unwrap(),
+            body: InvokeBody::Json(body),
+            headers: Default::default(),
+            invoke_key: INVOKE_KEY.to_string(),
+        },
+    )
+    .map(|body| body.deserialize().unwrap())
+}
+
+#[test]
+fn middleware_runs_in_order() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let builder = Builder::<MockRuntime>::new()
+        .commands(collect_commands![hello_world])
+        .middleware({
+            let calls = calls.clone();
+            move |ctx, next| {
+                calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("first:{}", ctx.command()));
+                next.run(ctx)
+            }
+        })
+        .middleware({
+            let calls = calls.clone();
+            move |ctx, next| {
+                calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("second:{}", ctx.window_label()));
+                next.run(ctx)
+            }
+        });
+    let (_app, webview) = mock_app(&builder);
+
+    assert_eq!(
+        invoke(&webview, "hello_world", json!({ "myName": "Oscar" })),
+        Ok(json!("Hello, Oscar!"))
+    );
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec!["first:hello_world".to_string(), "second:main".to_string()]
+    );
+}
+
+#[test]
+fn middleware_can_reject_commands() {
+    let builder = Builder::<MockRuntime>::new()
+        .commands(collect_commands![hello_world])
+        .middleware(|ctx, next| {
+            if let InvokeBody::Json(args) = ctx.arguments()
+                && args["myName"] == "Mallory"
+            {
+                return ctx.reject("Forbidden");
+            }
+
+            next.run(ctx)
+        });
+    let (_app, webview) = mock_app(&builder);
+
+    assert_eq!(
+        invoke(&webview, "hello_world", json!({ "myName": "Mallory" })),
+        Err(json!({ "__tauriSpectaRejected": "Forbidden" }))
+    );
+    assert_eq!(
+        invoke(&webview, "hello_world", json!({ "myName": "Oscar" })),
+        Ok(json!("Hello, Oscar!"))
+    );
+}
+
+#[test]
+fn middleware_observes_whether_commands_were_handled() {
+    let handled = Arc::new(Mutex::new(Vec::new()));
+    let builder = Builder::<MockRuntime>::new()
+        .commands(collect_commands![hello_world])
+        .middleware({
+            let handled = handled.clone();
+            move |ctx, next| {
+                let command = ctx.command().to_string();
+                let result = next.run(ctx);
+                handled.lock().unwrap().push((command, result));
+                result
+            }
+        });
+    let (_app, webview) = mock_app(&builder);
+
+    assert_eq!(
+        invoke(&webview, "hello_world", json!({ "myName": "Oscar" })),
+        Ok(json!("Hello, Oscar!"))
+    );
+    assert!(invoke(&webview, "missing", json!({})).is_err());
+
+    assert_eq!(
+        *handled.lock().unwrap(),
+        [
+            ("hello_world".to_string(), true),
+            ("missing".to_string(), false)
+        ]
+    );
+}
+
+#[test]
+fn catch_panics_rejects_with_panic_message() {
+    let builder = Builder::<MockRuntime>::new()
+        .commands(collect_commands![hello_world, explode])
+        .catch_panics();
+    let (_app, webview) = mock_app(&builder);
+
+    assert_eq!(
+        invoke(&webview, "explode", json!({})),
+        Err(json!({ "__tauriSpectaPanic": "boom" }))
+    );
+    assert_eq!(
+        invoke(&webview, "hello_world", json!({ "myName": "Oscar" })),
+        Ok(json!("Hello, Oscar!"))
+    );
+}
+
+#[test]
+fn expose_schema_describes_commands() {
+    let builder = Builder::<MockRuntime>::new()
+        .commands(collect_commands![hello_world, sum])
+        .constant("GREETING", "Hello")
+        .expose_schema();
+    let (_app, webview) = mock_app(&builder);
+
+    let schema = invoke(&webview, SCHEMA_COMMAND, json!({})).unwrap();
+    assert_eq!(schema["commands"][0]["name"], "hello_world");
+    assert_eq!(schema["commands"][0]["invokeName"], "hello_world");
+    assert_eq!(schema["commands"][0]["args"][0]["name"], "myName");
+    assert_eq!(
+        schema["commands"][0]["result"],
+        json!({ "kind": "primitive", "type": "String" })
+    );
+    assert_eq!(
+        schema["commands"][1]["args"][0]["type"],
+        json!({ "kind": "list", "item": { "kind": "primitive", "type": "u8" } })
+    );
+    assert_eq!(
+        schema["commands"][1]["result"],
+        json!({ "kind": "primitive", "type": "u32" })
+    );
+    assert_eq!(schema["constants"], json!({ "GREETING": "Hello" }));
+    assert_eq!(schema["hash"].as_str().unwrap().len(), 16);
+
+    // The hash only changes when the IPC surface does.
+    let (_app, webview) = mock_app(&builder);
+    assert_eq!(invoke(&webview, SCHEMA_COMMAND, json!({})).unwrap(), schema);
+    let (_app, webview) = mock_app(&builder.clone().constant("GREETING", "Hi"));
+    assert_ne!(
+        invoke(&webview, SCHEMA_COMMAND, json!({})).unwrap()["hash"],
+        schema["hash"]
+    );
+}
+
+#[test]
+fn typed_argument_errors_reject_invalid_arguments() {
+    let builder = Builder::<MockRuntime>::new()
+        .commands(collect_commands![hello_world, sum, greet_user])
+        .typed_argument_errors();
+    let (_app, webview) = mock_app(&builder);
+
+    assert_eq!(
+        invoke(&webview, "sum", json!({ "values": [1, 300] })),
+        Err(json!({
+            "__tauriSpectaInvalidArguments": {
+                "command": "sum",
+                "argument": "values",
+                "path": [1],
+                "message": "expected a u8, found 300",
+            },
+        }))
+    );
+    assert_eq!(
+        invoke(&webview, "hello_world", json!({})),
+        Err(json!({
+            "__tauriSpectaInvalidArguments": {
+                "command": "hello_world",
+                "argument": "myName",
+                "path": [],
+                "message": "missing required argument",
+            },
+        }))
+    );
+    assert_eq!(
+        invoke(&webview, "sum", json!({ "values": [1, 2] })),
+        Ok(json!(3))
+    );
+
+    // Commands which rename their arguments are validated with the key they were sent with.
+    assert_eq!(
+        invoke(&webview, "greet_user", json!({ "user_name": 5 })),
+        Err(json!({
+            "__tauriSpectaInvalidArguments": {
+                "command": "greet_user",
+                "argument": "user_name",
+                "path": [],
+                "message": "expected a string, found 5",
+            },
+        }))
+    );
+    assert_eq!(
+        invoke(&webview, "greet_user", json!({ "user_name": "Oscar" })),
+        Ok(json!("Hello, Oscar!"))
+    );
+}
+
+#[test]
+fn disabled_and_cfg_commands_are_removed() {
+    let builder = Builder::<MockRuntime>::new()
+        .commands(collect_commands![
+            hello_world,
+            sum,
+            #[cfg(any())]
+            explode,
+        ])
+        .disable_command("sum")
+        .expose_schema();
+    let (_app, webview) = mock_app(&builder);
+
+    let schema = invoke(&webview, SCHEMA_COMMAND, json!({})).unwrap();
+    assert_eq!(
+        schema["commands"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|command| command["name"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        vec!["hello_world"]
+    );
+    assert!(invoke(&webview, "sum", json!({ "values": [1, 2] })).is_err());
+    assert!(invoke(&webview, "explode", json!({})).is_err());
+    assert_eq!(
+        invoke(&webview, "hello_world", json!({ "myName": "Oscar" })),
+        Ok(json!("Hello, Oscar!"))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Can't disable command missing as it isn't registered!")]
+fn disabling_an_unknown_command_panics() {
+    let _ = Builder::<MockRuntime>::new()
+        .commands(collect_commands![hello_world])
+        .disable_command("missing");
+}
+
+#[test]
+fn sticky_events_return_the_last_payload() {
+    let builder = Builder::<MockRuntime>::new().events(collect_events![Status]);
+    let (app, webview) = mock_app(&builder);
+    builder.mount_events(&app);
+
+    let current = |event| invoke(&webview, STICKY_COMMAND, json!({ "event": event }));
+    assert_eq!(current("status"), Ok(json!(null)));
+
+    Status("connecting".into()).emit(&app).unwrap();
+    assert_eq!(current("status"), Ok(json!({ "payload": "connecting" })));
+
+    // Payloads emitted to other windows aren't returned.
+    Status("offline".into()).emit_to(&app, "settings").unwrap();
+    assert_eq!(current("status"), Ok(json!({ "payload": "connecting" })));
+    Status("online".into()).emit_to(&app, "main").unwrap();
+    assert_eq!(current("status"), Ok(json!({ "payload": "online" })));
+
+    assert!(invoke(&webview, STICKY_COMMAND, json!({})).is_err());
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn tracing_records_command_outcome() {
+    use std::{
+        fmt,
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    use tracing::{
+        Event as TracingEvent, Id, Metadata, Subscriber,
+        field::{Field, Visit},
+        span::{Attributes, Record},
+    };
+
+    /// Records the `outcome` field of every span.
+    #[derive(Default)]
+    struct Outcomes {
+        ids: AtomicU64,
+        outcomes: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Visit for &Outcomes {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "outcome" {
+                self.outcomes.lock().unwrap().push(value.to_string());
+            }
+        }
+
+        fn record_debug(&mut self, _: &Field, _: &dyn fmt::Debug) {}
+    }
+
+    impl Subscriber for Outcomes {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            span.record(&mut &*self);
+            Id::from_u64(self.ids.fetch_add(1, Ordering::Relaxed) + 1)
+        }
+
+        fn record(&self, _: &Id, values: &Record<'_>) {
+            values.record(&mut &*self);
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &TracingEvent<'_>) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    let subscriber = Outcomes::default();
+    let outcomes = subscriber.outcomes.clone();
+    let builder = Builder::<MockRuntime>::new().commands(collect_commands![hello_world, sum]);
+    let (_app, webview) = mock_app(&builder);
+
+    tracing::subscriber::with_default(subscriber, || {
+        assert!(invoke(&webview, "hello_world", json!({ "myName": "Oscar" })).is_ok());
+        assert!(invoke(&webview, "sum", json!({ "values": "not a list" })).is_err());
+    });
+
+    assert_eq!(*outcomes.lock().unwrap(), ["success", "error"]);
+}